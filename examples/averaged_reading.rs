@@ -1,6 +1,6 @@
 //! Example of reading averaged glucose data over time
 
-use libre_link_up_api_client::LibreLinkUpClient;
+use libre_link_up_api_client::{LibreLinkUpClient, TrendStrategy};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -18,19 +18,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             5,
             |average, memory, _history| {
                 println!("\n=== Average Calculated ===");
-                println!("Average value: {:.1} mg/dL", average.value);
+                println!("Average value: {:.1} mg/dL", average.mgdl);
                 println!("Average trend: {:?}", average.trend);
                 println!("Based on {} readings:", memory.len());
                 for (i, reading) in memory.iter().enumerate() {
-                    println!(
-                        "  {}. {:.1} mg/dL at {}",
-                        i + 1,
-                        reading.value,
-                        reading.date
-                    );
+                    println!("  {}. {:.1} mg/dL at {}", i + 1, reading.mgdl, reading.date);
                 }
             },
             15000, // 15 second intervals
+            TrendStrategy::MostRecent,
         )
         .await?;
 
@@ -17,7 +17,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Reading glucose data...");
     let data = client.read().await?;
 
-    println!("Current glucose: {:.1} mg/dL", data.current.value);
+    println!("Current glucose: {:.1} mg/dL", data.current.mgdl);
     println!("Trend: {:?}", data.current.trend);
     println!("Is high: {}", data.current.is_high);
     println!("Is low: {}", data.current.is_low);
@@ -32,6 +32,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         api_version: Some("4.16.0".to_string()),
         region: Some(Region::EU),
         connection_identifier: Some(ConnectionIdentifier::ByName("John Doe".to_string())),
+        ..Default::default()
     };
 
     let advanced_client = LibreLinkUpClient::new(config)?;
@@ -0,0 +1,242 @@
+//! Analysis primitives for glucose series (e.g. short-term variability for alerting).
+//!
+//! Main entries: [`rolling_std`] and [`split_by_sensor`]. All glucose values are in mg/dL.
+
+use crate::models::{client::LibreCgmData, common::ActiveSensor};
+use chrono::{DateTime, Duration, Utc};
+
+/// Running mean and variance (Welford) that supports removing values as well as adding them.
+#[derive(Debug, Default)]
+struct Welford {
+    count: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl Welford {
+    fn push(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    fn pop(&mut self, x: f64) {
+        self.count -= 1;
+        if self.count == 0 {
+            *self = Self::default();
+            return;
+        }
+        let delta = x - self.mean;
+        self.mean -= delta / self.count as f64;
+        self.m2 = (self.m2 - delta * (x - self.mean)).max(0.0);
+    }
+
+    /// Sample standard deviation (0 for fewer than two values)
+    fn std_dev(&self) -> f64 {
+        if self.count > 1 {
+            (self.m2 / (self.count - 1) as f64).sqrt()
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Sample standard deviation of glucose over a sliding time window.
+///
+/// Returns one `(timestamp, sd)` point per reading, where `sd` covers every reading in
+/// `(timestamp - window, timestamp]`. Readings are sorted by date first, and each one is added
+/// to and removed from the window once, so the whole series is computed in a single pass.
+/// A reading is always part of its own window, even if `window` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::{LibreCgmData, TrendType};
+/// use libre_link_up_api_client::analysis::rolling_std;
+/// use chrono::{Duration, TimeZone, Utc};
+///
+/// #[allow(deprecated)]
+/// let readings: Vec<LibreCgmData> = [100.0, 100.0, 160.0]
+///     .into_iter()
+///     .enumerate()
+///     .map(|(i, value)| LibreCgmData {
+///         value,
+///         mgdl: value,
+///         mmol: value / 18.0182,
+///         is_high: false,
+///         is_low: false,
+///         trend: TrendType::Flat,
+///         date: Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap() + Duration::minutes(15 * i as i64),
+///     })
+///     .collect();
+///
+/// let sd = rolling_std(&readings, Duration::minutes(30));
+/// assert_eq!(sd[1].1, 0.0);
+/// assert!((sd[2].1 - 42.426).abs() < 1e-3);
+/// ```
+pub fn rolling_std(readings: &[LibreCgmData], window: Duration) -> Vec<(DateTime<Utc>, f64)> {
+    let mut points: Vec<(DateTime<Utc>, f64)> = readings.iter().map(|r| (r.date, r.mgdl)).collect();
+    points.sort_by_key(|&(date, _)| date);
+
+    let mut stats = Welford::default();
+    let mut start = 0;
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &(date, mgdl))| {
+            stats.push(mgdl);
+            // The current reading always stays in its own window
+            while start < i && points[start].0 <= date - window {
+                stats.pop(points[start].1);
+                start += 1;
+            }
+            (date, stats.std_dev())
+        })
+        .collect()
+}
+
+/// Group readings by the sensor that was active when they were taken.
+///
+/// Each reading is assigned to the most recently activated sensor whose activation time
+/// ([`Sensor::activated_at`](crate::models::Sensor::activated_at)) is at or before the reading.
+/// Returns `(serial number, readings)` segments in chronological order; a new segment starts
+/// whenever the sensor changes, so rate or trend calculations can be run per segment without
+/// crossing a sensor change. Readings older than every known activation are grouped under an
+/// empty serial number.
+///
+/// # Examples
+///
+/// ```no_run
+/// use libre_link_up_api_client::LibreLinkUpClient;
+/// use libre_link_up_api_client::analysis::split_by_sensor;
+/// use libre_link_up_api_client::utils::map_glucose_data;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = LibreLinkUpClient::simple("email@example.com".to_string(), "password".to_string(), None)?;
+/// let raw = client.read_raw().await?;
+/// let readings: Vec<_> = raw.graph_data.iter().map(map_glucose_data).collect();
+/// for (serial, segment) in split_by_sensor(&readings, &raw.active_sensors) {
+///     println!("{}: {} readings", serial, segment.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn split_by_sensor(
+    readings: &[LibreCgmData],
+    sensors: &[ActiveSensor],
+) -> Vec<(String, Vec<LibreCgmData>)> {
+    let mut activations: Vec<(DateTime<Utc>, &str)> = sensors
+        .iter()
+        .filter_map(|s| Some((s.sensor.activated_at()?, s.sensor.sn.as_str())))
+        .collect();
+    activations.sort_by_key(|&(activated, _)| activated);
+
+    let mut sorted: Vec<&LibreCgmData> = readings.iter().collect();
+    sorted.sort_by_key(|r| r.date);
+
+    let mut segments: Vec<(String, Vec<LibreCgmData>)> = Vec::new();
+    for reading in sorted {
+        let serial = activations
+            .iter()
+            .rev()
+            .find(|&&(activated, _)| activated <= reading.date)
+            .map_or("", |&(_, sn)| sn);
+        match segments.last_mut() {
+            Some((current, segment)) if current == serial => segment.push(reading.clone()),
+            _ => segments.push((serial.to_string(), vec![reading.clone()])),
+        }
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::client::TrendType;
+    use chrono::TimeZone;
+
+    #[allow(deprecated)]
+    fn series(values: &[f64]) -> Vec<LibreCgmData> {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| LibreCgmData {
+                value,
+                mgdl: value,
+                mmol: value / 18.0182,
+                is_high: false,
+                is_low: false,
+                trend: TrendType::Flat,
+                date: start + Duration::minutes(15 * i as i64),
+            })
+            .collect()
+    }
+
+    fn naive_std(values: &[f64]) -> f64 {
+        if values.len() < 2 {
+            return 0.0;
+        }
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let var =
+            values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64;
+        var.sqrt()
+    }
+
+    #[test]
+    fn detects_variability_spike() {
+        let mut values = vec![110.0; 12];
+        values.extend([60.0, 190.0, 70.0, 200.0, 65.0, 185.0]);
+        values.extend([110.0; 12]);
+
+        // One hour covers four 15-minute readings
+        let sd = rolling_std(&series(&values), Duration::hours(1));
+        assert_eq!(sd.len(), values.len());
+        assert_eq!(sd[11].1, 0.0);
+        assert!(sd[15].1 > 60.0);
+        assert!(sd[values.len() - 1].1 < 1e-6);
+
+        for (i, &(_, actual)) in sd.iter().enumerate() {
+            let expected = naive_std(&values[i.saturating_sub(3)..=i]);
+            assert!((actual - expected).abs() < 1e-9, "index {i}");
+        }
+    }
+
+    #[test]
+    fn readings_split_at_sensor_change() {
+        let connection: crate::models::common::Connection =
+            serde_json::from_str(include_str!("../tests/fixtures/connection.json")).unwrap();
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let sensor = |sn: &str, activated: DateTime<Utc>| {
+            let mut sensor = connection.sensor.clone();
+            sensor.sn = sn.to_string();
+            sensor.a = activated.timestamp() as i32;
+            ActiveSensor {
+                sensor,
+                device: connection.patient_device.clone(),
+            }
+        };
+        // Second sensor starts after the fourth reading (readings are 15 minutes apart)
+        let sensors = [
+            sensor("NEW", start + Duration::minutes(50)),
+            sensor("OLD", start + Duration::minutes(15)),
+        ];
+
+        let segments = split_by_sensor(&series(&[100.0; 6]), &sensors);
+        let summary: Vec<(&str, usize)> = segments
+            .iter()
+            .map(|(sn, readings)| (sn.as_str(), readings.len()))
+            .collect();
+        assert_eq!(summary, [("", 1), ("OLD", 3), ("NEW", 2)]);
+    }
+
+    #[test]
+    fn unsorted_input_is_ordered_by_date() {
+        let mut readings = series(&[100.0, 120.0, 140.0]);
+        readings.reverse();
+        let sd = rolling_std(&readings, Duration::hours(1));
+        assert!(sd.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(sd[0].1, 0.0);
+    }
+}
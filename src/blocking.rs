@@ -0,0 +1,152 @@
+//! Synchronous client for scripts and CLIs that do not run an async runtime.
+//!
+//! Main entry: [`LibreLinkUpClient`]. Enabled by the `blocking` feature.
+//!
+//! The blocking client wraps the async [`crate::LibreLinkUpClient`] and drives it on its own
+//! single-threaded Tokio runtime, the same approach `reqwest::blocking` takes, so login,
+//! region redirects, retries, and the other configuration options behave identically. It shares
+//! the model types and [`LibreLinkUpError`](crate::LibreLinkUpError).
+//!
+//! Like `reqwest::blocking`, its methods must not be called from within an async runtime;
+//! they panic if they are. Use the async client there instead.
+
+use crate::{
+    client::ClientConfig,
+    errors::{LibreLinkUpError, Result},
+    models::{
+        client::{ReadRawResponse, ReadResponse},
+        countries::CountryConfigResponse,
+        logbook::LogbookResponse,
+    },
+};
+use tokio::runtime::{Builder, Runtime};
+
+/// Blocking counterpart of [`crate::LibreLinkUpClient`]
+///
+/// # Examples
+///
+/// ```no_run
+/// use libre_link_up_api_client::blocking::LibreLinkUpClient;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = LibreLinkUpClient::simple(
+///     "email@example.com".to_string(),
+///     "password".to_string(),
+///     None,
+/// )?;
+/// let data = client.read()?;
+/// println!("Current glucose: {:.1} mg/dL", data.current.mgdl);
+/// # Ok(())
+/// # }
+/// ```
+pub struct LibreLinkUpClient {
+    inner: crate::LibreLinkUpClient,
+    runtime: Runtime,
+}
+
+impl LibreLinkUpClient {
+    /// Create a blocking client with full configuration
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::LibreLinkUpClient::new`], plus
+    /// [`LibreLinkUpError::InvalidConfig`] if the runtime cannot be started.
+    pub fn new(config: ClientConfig) -> Result<Self> {
+        Self::wrap(crate::LibreLinkUpClient::new(config)?)
+    }
+
+    /// Create a blocking client with just username and password, see
+    /// [`crate::LibreLinkUpClient::simple`]
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::LibreLinkUpClient::simple`], plus
+    /// [`LibreLinkUpError::InvalidConfig`] if the runtime cannot be started.
+    pub fn simple(username: String, password: String, region: Option<String>) -> Result<Self> {
+        Self::wrap(crate::LibreLinkUpClient::simple(
+            username, password, region,
+        )?)
+    }
+
+    fn wrap(inner: crate::LibreLinkUpClient) -> Result<Self> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| {
+                LibreLinkUpError::InvalidConfig(format!("failed to start runtime: {}", e))
+            })?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// Read current and historical glucose data, see [`crate::LibreLinkUpClient::read`]
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::LibreLinkUpClient::read`].
+    pub fn read(&self) -> Result<ReadResponse> {
+        self.runtime.block_on(self.inner.read())
+    }
+
+    /// Read raw glucose data, see [`crate::LibreLinkUpClient::read_raw`]
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::LibreLinkUpClient::read_raw`].
+    pub fn read_raw(&self) -> Result<ReadRawResponse> {
+        self.runtime.block_on(self.inner.read_raw())
+    }
+
+    /// Get the logbook for a patient, see [`crate::LibreLinkUpClient::get_logbook`]
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::LibreLinkUpClient::get_logbook`].
+    pub fn get_logbook(&self, patient_id: &str) -> Result<LogbookResponse> {
+        self.runtime.block_on(self.inner.get_logbook(patient_id))
+    }
+
+    /// Fetch country/region config, see [`crate::LibreLinkUpClient::get_country_config`]
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::LibreLinkUpClient::get_country_config`].
+    pub fn get_country_config(
+        &self,
+        country: &str,
+        version: Option<&str>,
+    ) -> Result<CountryConfigResponse> {
+        self.runtime
+            .block_on(self.inner.get_country_config(country, version))
+    }
+
+    /// The underlying async client, e.g. for methods without a blocking counterpart
+    pub fn as_async(&self) -> &crate::LibreLinkUpClient {
+        &self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runtime_drives_timers_and_io() {
+        let client = LibreLinkUpClient::new(ClientConfig {
+            username: "email@example.com".to_string(),
+            password: "password".to_string(),
+            ..Default::default()
+        })
+        .unwrap();
+        // Throttling and timeouts need the time driver; requests need the IO driver
+        client.runtime.block_on(async {
+            tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+        });
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+        let result = client
+            .runtime
+            .block_on(reqwest::get(format!("http://{}/", addr)));
+        assert!(result.unwrap_err().is_connect());
+    }
+}
@@ -0,0 +1,170 @@
+//! Conversion of readings into the entry format used by xDrip+ and Nightscout bridges.
+//!
+//! Main entry: [`to_bridge_entries`]. Enabled by the `bridge` feature.
+//!
+//! LibreLinkUp only shares calibrated glucose values, not the raw sensor signal, so
+//! `unfiltered`/`filtered` carry the calibrated mg/dL value and `noise` is always 1 (clean).
+
+use crate::models::client::{LibreCgmData, ReadResponse, TrendType};
+use serde::{Deserialize, Serialize};
+
+/// A single `sgv` entry as accepted by xDrip+ and the Nightscout `/api/v1/entries` endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BridgeEntry {
+    /// Entry type; always `"sgv"`
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    /// Device name shown by the receiver
+    pub device: String,
+    /// Glucose in mg/dL
+    pub sgv: i32,
+    /// Trend direction name (e.g. `"FortyFiveUp"`)
+    pub direction: String,
+    /// Trend as the integer slope arrow (see [`xdrip_trend`])
+    pub trend: i32,
+    /// Reading time in Unix milliseconds
+    pub date: i64,
+    /// Reading time as an RFC 3339 string
+    pub date_string: String,
+    /// Unfiltered sensor value (the calibrated mg/dL value; no raw signal is available)
+    pub unfiltered: f64,
+    /// Filtered sensor value (the calibrated mg/dL value; no raw signal is available)
+    pub filtered: f64,
+    /// Signal noise level (1 = clean)
+    pub noise: i32,
+}
+
+/// xDrip+/Nightscout slope-arrow integer for a trend
+///
+/// The receivers use 1–9 (`DoubleUp` … `DoubleDown`, then `NOT COMPUTABLE` and
+/// `RATE OUT OF RANGE`). Libre has no double arrows, so its fastest arrows map to `SingleUp`
+/// (2) and `SingleDown` (6).
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::TrendType;
+/// use libre_link_up_api_client::bridge::xdrip_trend;
+///
+/// assert_eq!(xdrip_trend(TrendType::Flat), 4);
+/// assert_eq!(xdrip_trend(TrendType::NotComputable), 8);
+/// ```
+pub const fn xdrip_trend(trend: TrendType) -> i32 {
+    match trend {
+        TrendType::SingleUp => 2,
+        TrendType::FortyFiveUp => 3,
+        TrendType::Flat => 4,
+        TrendType::FortyFiveDown => 5,
+        TrendType::SingleDown => 6,
+        TrendType::NotComputable => 8,
+    }
+}
+
+/// xDrip+/Nightscout direction name for a trend
+pub const fn xdrip_direction(trend: TrendType) -> &'static str {
+    match trend {
+        TrendType::SingleUp => "SingleUp",
+        TrendType::FortyFiveUp => "FortyFiveUp",
+        TrendType::Flat => "Flat",
+        TrendType::FortyFiveDown => "FortyFiveDown",
+        TrendType::SingleDown => "SingleDown",
+        TrendType::NotComputable => "NOT COMPUTABLE",
+    }
+}
+
+/// Convert one reading into a bridge entry attributed to `device`
+pub fn to_bridge_entry(reading: &LibreCgmData, device: &str) -> BridgeEntry {
+    BridgeEntry {
+        entry_type: "sgv".to_string(),
+        device: device.to_string(),
+        sgv: reading.mgdl.round() as i32,
+        direction: xdrip_direction(reading.trend).to_string(),
+        trend: xdrip_trend(reading.trend),
+        date: reading.date.timestamp_millis(),
+        date_string: reading.date.to_rfc3339(),
+        unfiltered: reading.mgdl,
+        filtered: reading.mgdl,
+        noise: 1,
+    }
+}
+
+/// Convert the history and current reading of a [`ReadResponse`] into bridge entries
+///
+/// Entries are sorted oldest first, and the current reading is skipped if the history already
+/// contains a reading with the same timestamp.
+///
+/// # Examples
+///
+/// ```no_run
+/// use libre_link_up_api_client::LibreLinkUpClient;
+/// use libre_link_up_api_client::bridge::to_bridge_entries;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = LibreLinkUpClient::simple("email@example.com".to_string(), "password".to_string(), None)?;
+/// let response = client.read().await?;
+/// let body = serde_json::to_string(&to_bridge_entries(&response, "libre-link-up"))?;
+/// // POST `body` to <nightscout>/api/v1/entries
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_bridge_entries(response: &ReadResponse, device: &str) -> Vec<BridgeEntry> {
+    let mut readings: Vec<&LibreCgmData> = response.history.iter().collect();
+    if !readings.iter().any(|r| r.date == response.current.date) {
+        readings.push(&response.current);
+    }
+    readings.sort_by_key(|r| r.date);
+    readings
+        .into_iter()
+        .map(|reading| to_bridge_entry(reading, device))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GlucoseUnit;
+    use chrono::{Duration, TimeZone, Utc};
+
+    #[allow(deprecated)]
+    fn reading(minutes: i64, mgdl: f64, trend: TrendType) -> LibreCgmData {
+        LibreCgmData {
+            value: mgdl,
+            mgdl,
+            mmol: mgdl / 18.0182,
+            is_high: false,
+            is_low: false,
+            trend,
+            date: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::minutes(minutes),
+        }
+    }
+
+    #[test]
+    fn entries_are_sorted_and_deduplicated() {
+        let response = ReadResponse {
+            current: reading(30, 121.4, TrendType::FortyFiveUp),
+            history: vec![
+                reading(15, 110.0, TrendType::Flat),
+                reading(0, 100.0, TrendType::Flat),
+                reading(30, 121.4, TrendType::FortyFiveUp),
+            ],
+            target: None,
+            unit: GlucoseUnit::MgDl,
+        };
+
+        let entries = to_bridge_entries(&response, "test");
+        assert_eq!(entries.len(), 3);
+        assert!(entries.windows(2).all(|w| w[0].date < w[1].date));
+
+        let latest = entries.last().unwrap();
+        assert_eq!(latest.sgv, 121);
+        assert_eq!(
+            (latest.trend, latest.direction.as_str()),
+            (3, "FortyFiveUp")
+        );
+
+        let json = serde_json::to_value(latest).unwrap();
+        assert_eq!(json["type"], "sgv");
+        assert_eq!(json["dateString"], "2024-01-01T00:30:00+00:00");
+    }
+}
@@ -49,6 +49,11 @@ pub enum LibreLinkUpError {
     #[error("Unable to identify connection by given name '{0}'")]
     ConnectionNotFound(String),
 
+    #[error(
+        "Multiple patients match the name '{0}'. Use ConnectionIdentifier::ByFunction to pick one."
+    )]
+    DuplicateConnections(String),
+
     #[error("Unable to identify connection by given function")]
     ConnectionFunctionFailed,
 
@@ -63,7 +68,123 @@ pub enum LibreLinkUpError {
 
     #[error("Invalid API response: {0}")]
     InvalidResponse(String),
+
+    #[error("API error (status {status}): {message}")]
+    ApiError { status: i32, message: String },
+
+    #[error("Request to '{path}' failed - HTTP {status}: {body}")]
+    ApiStatus {
+        status: u16,
+        body: String,
+        path: String,
+    },
+
+    #[error("Rate limited by the API{}", match retry_after {
+        Some(wait) => format!("; retry after {} seconds", wait.as_secs()),
+        None => String::new(),
+    })]
+    RateLimited {
+        retry_after: Option<std::time::Duration>,
+    },
+
+    #[error("Operation did not complete before the deadline")]
+    Timeout,
+
+    #[error("Invalid client configuration: {0}")]
+    InvalidConfig(String),
+
+    #[error("Login failed after redirect to region '{region}': {source}")]
+    RedirectFailed {
+        region: String,
+        source: Box<LibreLinkUpError>,
+    },
+
+    #[error("Request failed after {attempts} attempts in {elapsed:?}: {source}")]
+    RetriesExhausted {
+        attempts: u32,
+        elapsed: std::time::Duration,
+        source: Box<LibreLinkUpError>,
+    },
+}
+
+impl LibreLinkUpError {
+    /// How long the server asked to wait before trying again, if it said
+    ///
+    /// Covers [`RateLimited`](Self::RateLimited) and the lockout of
+    /// [`AccountLocked`](Self::AccountLocked), also when wrapped in
+    /// [`RedirectFailed`](Self::RedirectFailed) or [`RetriesExhausted`](Self::RetriesExhausted).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libre_link_up_api_client::LibreLinkUpError;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(
+    ///     LibreLinkUpError::AccountLocked(300).retry_after(),
+    ///     Some(Duration::from_secs(300))
+    /// );
+    /// assert_eq!(LibreLinkUpError::BadCredentials.retry_after(), None);
+    /// ```
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        match self {
+            Self::RateLimited { retry_after } => *retry_after,
+            Self::AccountLocked(lockout) => Some(std::time::Duration::from_secs(
+                u64::try_from(*lockout).ok()?,
+            )),
+            Self::RedirectFailed { source, .. } | Self::RetriesExhausted { source, .. } => {
+                source.retry_after()
+            }
+            _ => None,
+        }
+    }
+
+    /// Attach the region a login was redirected to, so failures on the new region say where
+    /// they happened.
+    ///
+    /// Account errors ([`BadCredentials`](Self::BadCredentials),
+    /// [`AccountLocked`](Self::AccountLocked),
+    /// [`AdditionalActionRequired`](Self::AdditionalActionRequired)) and
+    /// [`RateLimited`](Self::RateLimited) are returned unchanged so they can still be matched
+    /// directly, as are errors that already carry a region.
+    pub(crate) fn in_redirected_region(self, region: &str) -> Self {
+        match self {
+            Self::BadCredentials
+            | Self::AccountLocked(_)
+            | Self::AdditionalActionRequired(_)
+            | Self::RateLimited { .. }
+            | Self::RedirectFailed { .. } => self,
+            source => Self::RedirectFailed {
+                region: region.to_string(),
+                source: Box::new(source),
+            },
+        }
+    }
 }
 
 /// Result type for LibreLinkUp API operations.
 pub type Result<T> = std::result::Result<T, LibreLinkUpError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redirect_failures_name_the_final_region() {
+        let err =
+            LibreLinkUpError::InvalidResponse("HTTP 403".to_string()).in_redirected_region("eu2");
+        assert!(matches!(&err, LibreLinkUpError::RedirectFailed { region, .. } if region == "eu2"));
+        assert!(err.to_string().contains("'eu2'"));
+        assert!(err.to_string().contains("HTTP 403"));
+
+        // A nested redirect keeps the innermost (final) region
+        let err = err.in_redirected_region("us");
+        assert!(err.to_string().contains("'eu2'"));
+        assert!(!err.to_string().contains("'us'"));
+
+        assert!(matches!(
+            LibreLinkUpError::BadCredentials.in_redirected_region("eu2"),
+            LibreLinkUpError::BadCredentials
+        ));
+    }
+}
@@ -5,10 +5,11 @@
 //!
 //! ## Main API
 //!
-//! - **[`LibreLinkUpClient`]** — main client; use [`simple`](client::LibreLinkUpClient::simple) or [`new`](client::LibreLinkUpClient::new) to construct.
+//! - **[`LibreLinkUpClient`]** — main client; use [`simple`](client::LibreLinkUpClient::simple) or [`new`](client::LibreLinkUpClient::new) to construct, or [`with_token`](client::LibreLinkUpClient::with_token) to reuse an existing token.
 //! - **[`LibreLinkUpClient::get_country_config`](client::LibreLinkUpClient::get_country_config)** — unauthenticated country/region config.
 //! - **[`ClientConfig`]** — client configuration (username, password, region, etc.).
-//! - **[`ConnectionIdentifier`]** — how to pick a patient when following multiple (e.g. by name).
+//! - **[`ConnectionIdentifier`]** — how to pick a patient when following multiple (e.g. by name);
+//!   [`get_connections`](client::LibreLinkUpClient::get_connections) lists the followed patients.
 //! - **[`LibreLinkUpError`]** — error type for all operations.
 //! - **[`Result<T>`](errors::Result)** — alias for `Result<T, LibreLinkUpError>`.
 //!
@@ -16,7 +17,25 @@
 //!
 //! Re-exported at crate root: [`ReadResponse`], [`ReadRawResponse`], [`UserResponse`], [`AccountResponse`],
 //! [`LogbookResponse`], [`NotificationSettingsResponse`], [`CountryConfigResponse`], [`LibreCgmData`],
-//! [`TrendType`], [`GlucoseItem`], [`Connection`], [`Region`]. See the [models] module for the full set.
+//! [`TrendType`], [`TimeSeries`], [`GlucoseItem`], [`Connection`], [`Region`]. See the [models] module for the full set.
+//!
+//! ## Reports
+//!
+//! The [report] module computes standardized CGM metrics (TIR, TBR/TAR tiers, GMI, CV, GRI)
+//! from [`LibreCgmData`] readings. The [analysis] module has lower-level primitives such as
+//! rolling variability.
+//!
+//! ## Feature flags
+//!
+//! - **`timezone`** — local-time accessors (`LibreCgmData::local_date`) and per-day report
+//!   grouping via [`chrono-tz`](https://docs.rs/chrono-tz).
+//! - **`bridge`** — conversion of readings into xDrip+/Nightscout `sgv` entries
+//!   (`bridge` module).
+//! - **`blocking`** — synchronous client for code without an async runtime (`blocking`
+//!   module).
+//! - **`tracing`** — [`tracing`](https://docs.rs/tracing) spans for authenticated requests and
+//!   events for logins, region redirects, and re-authentication. Nothing is emitted unless a
+//!   subscriber is installed.
 //!
 //! ## Quick Start
 //!
@@ -35,7 +54,7 @@
 //!     // Read current glucose data
 //!     let data = client.read().await?;
 //!
-//!     println!("Current glucose: {:.1} mg/dL", data.current.value);
+//!     println!("Current glucose: {:.1} mg/dL", data.current.mgdl);
 //!     println!("Trend: {:?}", data.current.trend);
 //!     println!("Historical readings: {}", data.history.len());
 //!
@@ -57,6 +76,7 @@
 //!     api_version: None,
 //!     region: Some(Region::EU),
 //!     connection_identifier: None,
+//!     ..Default::default()
 //! };
 //!
 //! let client = LibreLinkUpClient::new(config)?;
@@ -85,6 +105,32 @@
 //! # }
 //! ```
 //!
+//! ### Polling
+//!
+//! Readings update about once a minute. When polling in a loop, sleep between reads or set
+//! [`ClientConfig::min_request_interval`](client::ClientConfig::min_request_interval) so a
+//! missing sleep cannot hammer the API and get the account locked out:
+//!
+//! ```no_run
+//! use libre_link_up_api_client::{ClientConfig, LibreLinkUpClient};
+//! use std::time::Duration;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = LibreLinkUpClient::new(ClientConfig {
+//!     username: "email@example.com".to_string(),
+//!     password: "password".to_string(),
+//!     min_request_interval: Some(Duration::from_secs(60)),
+//!     ..Default::default()
+//! })?;
+//!
+//! loop {
+//!     // Waits if the previous read was less than a minute ago
+//!     let data = client.read().await?;
+//!     println!("{:.1} mg/dL", data.current.mgdl);
+//! }
+//! # }
+//! ```
+//!
 //! ## Error Handling
 //!
 //! All API operations return [`Result<T, LibreLinkUpError>`](errors::LibreLinkUpError).
@@ -101,7 +147,7 @@
 //!
 //! match client {
 //!     Ok(client) => match client.read().await {
-//!         Ok(data) => println!("Success: {:.1} mg/dL", data.current.value),
+//!         Ok(data) => println!("Success: {:.1} mg/dL", data.current.mgdl),
 //!         Err(LibreLinkUpError::NoConnections) => {
 //!             eprintln!("No patients followed. Start following someone in the app.");
 //!         }
@@ -112,15 +158,33 @@
 //! # }
 //! ```
 
+pub mod analysis;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "bridge")]
+pub mod bridge;
 pub mod client;
 pub mod errors;
 pub mod models;
+pub mod reconnect;
+pub mod report;
+pub mod retry;
+mod trace;
 pub mod utils;
+pub mod version;
 
-pub use client::{ClientConfig, ConnectionIdentifier, LibreLinkUpClient};
+#[cfg(feature = "timezone")]
+pub use chrono_tz::Tz;
+pub use client::{
+    Certificate, ClientConfig, ConnectionIdentifier, LibreLinkUpClient, ResponseMeta,
+    ResponseStats, SessionState, TlsVersion,
+};
 pub use errors::{LibreLinkUpError, Result};
 pub use models::{
-    AccountResponse, Connection, CountryConfigData, CountryConfigResponse, GlucoseItem,
-    LibreCgmData, LogbookEntry, LogbookResponse, NotificationSettingsResponse, ReadRawResponse,
-    ReadResponse, Region, TrendType, UserResponse,
+    AccountResponse, Connection, CountryConfigData, CountryConfigResponse, GlucoseClassification,
+    GlucoseItem, GlucoseUnit, LibreCgmData, LogbookEntry, LogbookResponse,
+    NotificationSettingsResponse, ReadRawResponse, ReadResponse, Region, Reliability, TimeSeries,
+    TrendStrategy, TrendType, UserResponse,
 };
+pub use reconnect::{ReconnectEvent, ReconnectPolicy};
+pub use retry::RetryConfig;
@@ -1,8 +1,15 @@
 //! Processed glucose and read-response types ([`read`](crate::LibreLinkUpClient::read), [`read_raw`](crate::LibreLinkUpClient::read_raw)).
 
-use crate::models::common::{ActiveSensor, Connection, GlucoseItem};
-use chrono::{DateTime, Utc};
+use crate::errors::LibreLinkUpError;
+use crate::models::common::{
+    ActiveSensor, Connection, GlucoseItem, MeasurementColor, PatientDevice,
+};
+use crate::report::{GlucoseStats, TargetRange};
+use crate::utils::{TREND_MAP, try_map_glucose_data};
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io::{self, Write};
 
 /// Trend direction for glucose readings (matches API trend arrow).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -21,27 +28,274 @@ pub enum TrendType {
     NotComputable,
 }
 
+impl TrendType {
+    /// Variant name, as used by serde; stable across releases, unlike the arrow
+    /// [`Display`](fmt::Display) form
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libre_link_up_api_client::TrendType;
+    ///
+    /// assert_eq!(TrendType::FortyFiveUp.as_str(), "FortyFiveUp");
+    /// ```
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            TrendType::SingleDown => "SingleDown",
+            TrendType::FortyFiveDown => "FortyFiveDown",
+            TrendType::Flat => "Flat",
+            TrendType::FortyFiveUp => "FortyFiveUp",
+            TrendType::SingleUp => "SingleUp",
+            TrendType::NotComputable => "NotComputable",
+        }
+    }
+
+    /// Approximate rate of change each arrow represents, as `(min, max)` in mg/dL per minute
+    ///
+    /// These follow Abbott's arrow definitions for FreeStyle Libre: rising or falling quickly is
+    /// more than 2 mg/dL/min, rising or falling is 1–2 mg/dL/min, and stable is less than
+    /// 1 mg/dL/min either way. The outer arrows are open-ended, so their far bound is infinite,
+    /// and [`NotComputable`](Self::NotComputable) spans every rate. Use this only as a coarse
+    /// estimate when there is too little history to compute a real rate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libre_link_up_api_client::TrendType;
+    ///
+    /// assert_eq!(TrendType::FortyFiveUp.approximate_rate(), (1.0, 2.0));
+    /// assert_eq!(TrendType::Flat.approximate_rate(), (-1.0, 1.0));
+    /// assert_eq!(TrendType::SingleDown.approximate_rate(), (f64::NEG_INFINITY, -2.0));
+    /// ```
+    pub const fn approximate_rate(&self) -> (f64, f64) {
+        match self {
+            TrendType::SingleDown => (f64::NEG_INFINITY, -2.0),
+            TrendType::FortyFiveDown => (-2.0, -1.0),
+            TrendType::Flat => (-1.0, 1.0),
+            TrendType::FortyFiveUp => (1.0, 2.0),
+            TrendType::SingleUp => (2.0, f64::INFINITY),
+            TrendType::NotComputable => (f64::NEG_INFINITY, f64::INFINITY),
+        }
+    }
+}
+
+/// How [`read_averaged`](crate::LibreLinkUpClient::read_averaged) derives the trend of an
+/// averaged reading
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::TrendStrategy;
+///
+/// assert_eq!(TrendStrategy::default(), TrendStrategy::MostRecent);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum TrendStrategy {
+    /// Trend of the latest reading, i.e. where glucose is heading now
+    #[default]
+    MostRecent,
+    /// Most common trend among the readings; ties go to the more recent one
+    Majority,
+    /// Rounded mean of the readings' [`TREND_MAP`] indices
+    ///
+    /// Readings that oscillate between rising and falling average out to a trend none of them
+    /// had.
+    Averaged,
+}
+
+impl TrendStrategy {
+    /// Trend for `readings` (oldest first), whose latest trend is `latest`
+    pub(crate) fn combine(self, readings: &[LibreCgmData], latest: TrendType) -> TrendType {
+        match self {
+            TrendStrategy::MostRecent => latest,
+            // `max_by_key` keeps the last of equal maxima, i.e. the most recent
+            TrendStrategy::Majority => readings
+                .iter()
+                .map(|reading| reading.trend)
+                .max_by_key(|&trend| {
+                    readings
+                        .iter()
+                        .filter(|reading| reading.trend == trend)
+                        .count()
+                })
+                .unwrap_or(latest),
+            TrendStrategy::Averaged => {
+                let indices: Vec<usize> = readings
+                    .iter()
+                    .filter_map(|reading| TREND_MAP.iter().position(|&t| t == reading.trend))
+                    .collect();
+                if indices.is_empty() {
+                    return TrendType::Flat;
+                }
+                let mean = indices.iter().sum::<usize>() as f64 / indices.len() as f64;
+                TREND_MAP
+                    .get(mean.round() as usize)
+                    .copied()
+                    .unwrap_or(TrendType::Flat)
+            }
+        }
+    }
+}
+
+/// API trend arrow index (the reverse of [`TREND_MAP`])
+///
+/// The API uses both 0 and 6 for [`NotComputable`](TrendType::NotComputable); 0 is the
+/// canonical index returned here.
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::TrendType;
+///
+/// assert_eq!(i32::from(TrendType::Flat), 3);
+/// assert_eq!(i32::from(TrendType::NotComputable), 0);
+/// ```
+impl From<TrendType> for i32 {
+    fn from(trend: TrendType) -> Self {
+        match trend {
+            TrendType::NotComputable => 0,
+            TrendType::SingleDown => 1,
+            TrendType::FortyFiveDown => 2,
+            TrendType::Flat => 3,
+            TrendType::FortyFiveUp => 4,
+            TrendType::SingleUp => 5,
+        }
+    }
+}
+
+/// Trend for an API trend arrow index, via [`TREND_MAP`]
+///
+/// Unlike [`get_trend`](crate::utils::get_trend), which falls back to `Flat`, indices outside
+/// 0–6 are rejected with [`LibreLinkUpError::InvalidResponse`].
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::TrendType;
+///
+/// assert_eq!(TrendType::try_from(4).unwrap(), TrendType::FortyFiveUp);
+/// assert_eq!(TrendType::try_from(6).unwrap(), TrendType::NotComputable);
+/// assert!(TrendType::try_from(7).is_err());
+/// ```
+impl TryFrom<i32> for TrendType {
+    type Error = LibreLinkUpError;
+
+    fn try_from(arrow: i32) -> Result<Self, Self::Error> {
+        usize::try_from(arrow)
+            .ok()
+            .and_then(|index| TREND_MAP.get(index).copied())
+            .ok_or_else(|| {
+                LibreLinkUpError::InvalidResponse(format!("unknown trend arrow {}", arrow))
+            })
+    }
+}
+
+/// Arrow for the trend: `↓`, `↘`, `→`, `↗`, `↑`, or `?` when not computable
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::TrendType;
+///
+/// assert_eq!(TrendType::FortyFiveDown.to_string(), "↘");
+/// assert_eq!(format!("{:>3}", TrendType::Flat), "  →");
+/// ```
+impl fmt::Display for TrendType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            TrendType::SingleDown => "↓",
+            TrendType::FortyFiveDown => "↘",
+            TrendType::Flat => "→",
+            TrendType::FortyFiveUp => "↗",
+            TrendType::SingleUp => "↑",
+            TrendType::NotComputable => "?",
+        })
+    }
+}
+
+/// Glucose unit for output values
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::GlucoseUnit;
+///
+/// assert_eq!(GlucoseUnit::MmolL.round(6.2168), 6.2);
+/// assert_eq!(GlucoseUnit::MgDl.round(112.4), 112.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum GlucoseUnit {
+    /// Milligrams per decilitre
+    #[default]
+    MgDl,
+    /// Millimoles per litre
+    MmolL,
+}
+
+impl GlucoseUnit {
+    /// Round `value` to the precision readings are displayed with in this unit (whole numbers
+    /// for mg/dL, one decimal for mmol/L)
+    pub fn round(self, value: f64) -> f64 {
+        match self {
+            GlucoseUnit::MgDl => value.round(),
+            GlucoseUnit::MmolL => (value * 10.0).round() / 10.0,
+        }
+    }
+}
+
+/// Where a reading falls relative to a set of thresholds, see [`LibreCgmData::classify`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GlucoseClassification {
+    /// Below the urgent low threshold
+    UrgentLow,
+    /// Below the low threshold
+    Low,
+    /// Between the low and high thresholds (inclusive)
+    InRange,
+    /// Above the high threshold
+    High,
+    /// Above the urgent high threshold
+    UrgentHigh,
+}
+
 /// Processed glucose data for consumption
 ///
 /// # Examples
 ///
 /// ```
 /// use libre_link_up_api_client::{LibreCgmData, TrendType};
+/// use libre_link_up_api_client::utils::mgdl_to_mmol;
 /// use chrono::Utc;
 ///
+/// #[allow(deprecated)]
 /// let data = LibreCgmData {
 ///     value: 120.0,
+///     mgdl: 120.0,
+///     mmol: mgdl_to_mmol(120.0),
 ///     is_high: false,
 ///     is_low: false,
 ///     trend: TrendType::Flat,
 ///     date: Utc::now(),
 /// };
-/// assert_eq!(data.value, 120.0);
+/// assert_eq!(data.mgdl, 120.0);
+/// assert!((data.mmol - 6.66).abs() < 0.01);
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LibreCgmData {
-    /// Glucose value in mg/dL
+    /// Glucose value as reported by the API, in the account's configured unit, or in
+    /// [`ClientConfig::output_unit`](crate::ClientConfig::output_unit) when that is set
+    #[deprecated(
+        since = "0.2.2",
+        note = "unit depends on the account settings; use `mgdl` or `mmol` instead"
+    )]
     pub value: f64,
+    /// Glucose value in mg/dL (`0.0` when deserialized from data saved before 0.2.2)
+    #[serde(default)]
+    pub mgdl: f64,
+    /// Glucose value in mmol/L (computed from `mgdl`; `0.0` when deserialized from data saved
+    /// before 0.2.2)
+    #[serde(default)]
+    pub mmol: f64,
     /// Whether the value is above the target high
     #[serde(rename = "isHigh")]
     pub is_high: bool,
@@ -54,6 +308,150 @@ pub struct LibreCgmData {
     pub date: DateTime<Utc>,
 }
 
+impl LibreCgmData {
+    /// Glucose value in `unit` (unrounded)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libre_link_up_api_client::{GlucoseUnit, LibreCgmData, TrendType};
+    /// use chrono::Utc;
+    ///
+    /// #[allow(deprecated)]
+    /// let data = LibreCgmData {
+    ///     value: 180.0,
+    ///     mgdl: 180.0,
+    ///     mmol: 180.0 / 18.0182,
+    ///     is_high: false,
+    ///     is_low: false,
+    ///     trend: TrendType::Flat,
+    ///     date: Utc::now(),
+    /// };
+    /// assert_eq!(data.value_in(GlucoseUnit::MgDl), 180.0);
+    /// assert_eq!(GlucoseUnit::MmolL.round(data.value_in(GlucoseUnit::MmolL)), 10.0);
+    /// ```
+    pub fn value_in(&self, unit: GlucoseUnit) -> f64 {
+        match unit {
+            GlucoseUnit::MgDl => self.mgdl,
+            GlucoseUnit::MmolL => self.mmol,
+        }
+    }
+
+    /// Classify the reading against thresholds in mg/dL
+    ///
+    /// The bounds follow [`TargetRange`]: `low..=high` is in range, and a reading is urgent
+    /// once it is strictly below `urgent_low` or above `urgent_high`. The thresholds are
+    /// compared with [`mgdl`](Self::mgdl), never with the deprecated `value`, whose unit depends
+    /// on the account; use [`classify_in`](Self::classify_in) for mmol/L thresholds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libre_link_up_api_client::{GlucoseClassification, LibreCgmData, TrendType};
+    /// use chrono::Utc;
+    ///
+    /// #[allow(deprecated)]
+    /// let data = LibreCgmData {
+    ///     value: 62.0,
+    ///     mgdl: 62.0,
+    ///     mmol: 62.0 / 18.0182,
+    ///     is_high: false,
+    ///     is_low: true,
+    ///     trend: TrendType::Flat,
+    ///     date: Utc::now(),
+    /// };
+    /// assert_eq!(data.classify(70.0, 180.0, 54.0, 250.0), GlucoseClassification::Low);
+    /// ```
+    pub fn classify(
+        &self,
+        low: f64,
+        high: f64,
+        urgent_low: f64,
+        urgent_high: f64,
+    ) -> GlucoseClassification {
+        self.classify_in(GlucoseUnit::MgDl, low, high, urgent_low, urgent_high)
+    }
+
+    /// Like [`classify`](Self::classify), but with thresholds in `unit`
+    pub fn classify_in(
+        &self,
+        unit: GlucoseUnit,
+        low: f64,
+        high: f64,
+        urgent_low: f64,
+        urgent_high: f64,
+    ) -> GlucoseClassification {
+        let value = self.value_in(unit);
+        if value < urgent_low {
+            GlucoseClassification::UrgentLow
+        } else if value < low {
+            GlucoseClassification::Low
+        } else if value > urgent_high {
+            GlucoseClassification::UrgentHigh
+        } else if value > high {
+            GlucoseClassification::High
+        } else {
+            GlucoseClassification::InRange
+        }
+    }
+
+    /// Whether the value is below the device's fixed (urgent) low alarm threshold
+    ///
+    /// Unlike [`is_low`](Self::is_low), which follows the patient's adjustable target low, the
+    /// fixed low threshold (typically 55 mg/dL) cannot be configured away and marks a
+    /// dangerously low reading. Returns `None` if the device settings carry no fixed low
+    /// threshold, rather than comparing against a made-up one.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - The patient's device settings (`Connection::patient_device`)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use libre_link_up_api_client::LibreLinkUpClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = LibreLinkUpClient::simple("email@example.com".to_string(), "password".to_string(), None)?;
+    /// let (connection, reading) = client.freshest_connection().await?;
+    /// if reading.is_urgent_low(&connection.patient_device) == Some(true) {
+    ///     println!("Urgent low: {} mg/dL", reading.mgdl);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_urgent_low(&self, device: &PatientDevice) -> Option<bool> {
+        let threshold = device.fixed_low_alarm_values.as_ref()?;
+        Some(self.mgdl < threshold.mgdl)
+    }
+
+    /// Timestamp of the reading converted to `tz`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libre_link_up_api_client::{LibreCgmData, TrendType};
+    /// use chrono::{TimeZone, Timelike, Utc};
+    /// use chrono_tz::Europe::Berlin;
+    ///
+    /// #[allow(deprecated)]
+    /// let data = LibreCgmData {
+    ///     value: 120.0,
+    ///     mgdl: 120.0,
+    ///     mmol: 120.0 / 18.0182,
+    ///     is_high: false,
+    ///     is_low: false,
+    ///     trend: TrendType::Flat,
+    ///     date: Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+    /// };
+    /// assert_eq!(data.local_date(Berlin).hour(), 13);
+    /// ```
+    #[cfg(feature = "timezone")]
+    pub fn local_date(&self, tz: chrono_tz::Tz) -> DateTime<chrono_tz::Tz> {
+        self.date.with_timezone(&tz)
+    }
+}
+
 /// Response from the read() method containing current and historical glucose data
 ///
 /// # Examples
@@ -69,7 +467,7 @@ pub struct LibreCgmData {
 /// )?;
 ///
 /// let response = client.read().await?;
-/// println!("Current: {:.1} mg/dL", response.current.value);
+/// println!("Current: {:.1} mg/dL", response.current.mgdl);
 /// println!("History: {} readings", response.history.len());
 /// # Ok(())
 /// # }
@@ -80,12 +478,331 @@ pub struct ReadResponse {
     pub current: LibreCgmData,
     /// Historical glucose readings
     pub history: Vec<LibreCgmData>,
+    /// The patient's target range from their connection, `None` if it is not set
+    #[serde(default)]
+    pub target: Option<TargetRange>,
+    /// Unit of the readings' `value`: [`ClientConfig::output_unit`](crate::ClientConfig::output_unit)
+    /// if set, otherwise the unit the API reported the current measurement in
+    #[serde(default)]
+    pub unit: GlucoseUnit,
+}
+
+impl ReadResponse {
+    /// Glucose readings (mg/dL) as a time series sorted by date
+    ///
+    /// Includes the history plus the current reading if it is newer than the last history
+    /// point.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use libre_link_up_api_client::LibreLinkUpClient;
+    /// use chrono::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = LibreLinkUpClient::simple(
+    ///     "email@example.com".to_string(),
+    ///     "password".to_string(),
+    ///     None,
+    /// )?;
+    ///
+    /// let series = client.read().await?.time_series();
+    /// let hourly = series.resample(Duration::hours(1));
+    /// for (date, value) in hourly.points() {
+    ///     println!("{}: {:.0} mg/dL", date, value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn time_series(&self) -> TimeSeries {
+        TimeSeries(
+            self.readings_by_date()
+                .into_iter()
+                .map(|d| (d.date, d.mgdl))
+                .collect(),
+        )
+    }
+
+    /// History sorted by date, plus the current reading if it is newer than the last one
+    fn readings_by_date(&self) -> Vec<&LibreCgmData> {
+        let mut readings: Vec<&LibreCgmData> = self.history.iter().collect();
+        readings.sort_by_key(|d| d.date);
+        if readings.last().is_none_or(|d| d.date < self.current.date) {
+            readings.push(&self.current);
+        }
+        readings
+    }
+
+    /// Readings as CSV, see [`to_csv_writer`](Self::to_csv_writer)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use libre_link_up_api_client::LibreLinkUpClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = LibreLinkUpClient::simple("email@example.com".to_string(), "password".to_string(), None)?;
+    /// let csv = client.read().await?.to_csv();
+    /// std::fs::write("readings.csv", csv)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let mut csv = Vec::new();
+        self.to_csv_writer(&mut csv)
+            .expect("writing to a Vec cannot fail");
+        String::from_utf8(csv).expect("CSV output is ASCII")
+    }
+
+    /// Write the readings as CSV to `writer`
+    ///
+    /// Writes a `date,mgdl,mmol,trend,is_high,is_low` header and one row per reading, in the
+    /// order of [`time_series`](Self::time_series) (the history by date, then the current
+    /// reading if it is newer). Dates are RFC 3339 in UTC, trends use
+    /// [`TrendType::as_str`], and flags are `true`/`false`; no field needs quoting.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `writer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use libre_link_up_api_client::{GlucoseUnit, LibreCgmData, ReadResponse, TrendType};
+    ///
+    /// #[allow(deprecated)]
+    /// let current = LibreCgmData {
+    ///     value: 113.0,
+    ///     mgdl: 113.0,
+    ///     mmol: 6.3,
+    ///     is_high: false,
+    ///     is_low: false,
+    ///     trend: TrendType::Flat,
+    ///     date: Utc.with_ymd_and_hms(2023, 11, 14, 10, 15, 0).unwrap(),
+    /// };
+    /// let response = ReadResponse {
+    ///     current,
+    ///     history: Vec::new(),
+    ///     target: None,
+    ///     unit: GlucoseUnit::MgDl,
+    /// };
+    ///
+    /// let mut csv = Vec::new();
+    /// response.to_csv_writer(&mut csv)?;
+    /// assert_eq!(
+    ///     String::from_utf8(csv)?,
+    ///     "date,mgdl,mmol,trend,is_high,is_low\n2023-11-14T10:15:00Z,113,6.3,Flat,false,false\n"
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_csv_writer<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "date,mgdl,mmol,trend,is_high,is_low")?;
+        for reading in self.readings_by_date() {
+            writeln!(
+                writer,
+                "{},{},{},{},{},{}",
+                reading.date.to_rfc3339_opts(SecondsFormat::Secs, true),
+                reading.mgdl,
+                reading.mmol,
+                reading.trend.as_str(),
+                reading.is_high,
+                reading.is_low
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Time-weighted average glucose in mg/dL over the span of [`time_series`](Self::time_series)
+    ///
+    /// Integrates glucose over time with the trapezoidal rule and divides by the total time
+    /// span, so each reading counts in proportion to the time around it. The plain mean used by
+    /// [`agp_metrics`](crate::report::agp_metrics) weights every reading equally, which
+    /// overweights densely sampled periods (e.g. the current reading right after the last
+    /// 15-minute history point) and underweights readings either side of a gap. GMI is derived
+    /// from that plain mean, so it is not directly comparable with this value.
+    ///
+    /// Returns `None` if there are no readings, and the single value if all readings share one
+    /// timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libre_link_up_api_client::{GlucoseUnit, LibreCgmData, ReadResponse, TrendType};
+    /// use chrono::{Duration, TimeZone, Utc};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    /// #[allow(deprecated)]
+    /// let reading = |minutes: i64, mgdl: f64| LibreCgmData {
+    ///     value: mgdl,
+    ///     mgdl,
+    ///     mmol: mgdl / 18.0182,
+    ///     is_high: false,
+    ///     is_low: false,
+    ///     trend: TrendType::Flat,
+    ///     date: start + Duration::minutes(minutes),
+    /// };
+    ///
+    /// let response = ReadResponse {
+    ///     current: reading(60, 200.0),
+    ///     history: vec![reading(0, 100.0), reading(30, 100.0)],
+    ///     target: None,
+    ///     unit: GlucoseUnit::MgDl,
+    /// };
+    /// // 30 minutes at 100 mg/dL, then 30 minutes rising linearly to 200 mg/dL
+    /// assert_eq!(response.time_weighted_average(), Some(125.0));
+    /// ```
+    pub fn time_weighted_average(&self) -> Option<f64> {
+        let series = self.time_series();
+        let points = series.points();
+        let (first, last) = (points.first()?, points.last()?);
+
+        let span = (last.0 - first.0).num_milliseconds() as f64;
+        if span <= 0.0 {
+            return Some(points.iter().map(|(_, v)| v).sum::<f64>() / points.len() as f64);
+        }
+
+        let area: f64 = points
+            .windows(2)
+            .map(|pair| {
+                let dt = (pair[1].0 - pair[0].0).num_milliseconds() as f64;
+                dt * (pair[0].1 + pair[1].1) / 2.0
+            })
+            .sum();
+        Some(area / span)
+    }
+
+    /// Average, GMI, and time in/below/above the target range `low..=high` (mg/dL) of the
+    /// history readings
+    ///
+    /// [`ReadRawResponse::stats`] uses the patient's own target range instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use libre_link_up_api_client::LibreLinkUpClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = LibreLinkUpClient::simple("email@example.com".to_string(), "password".to_string(), None)?;
+    /// let response = client.read().await?;
+    /// let target = response.target.unwrap_or_default();
+    /// let stats = response.stats(target.low, target.high);
+    /// println!("TIR {:.0}%, GMI {:.1?}%", stats.time_in_range, stats.gmi);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stats(&self, low: f64, high: f64) -> GlucoseStats {
+        GlucoseStats::from_readings(&self.history, low, high)
+    }
+}
+
+/// Time-ordered series of `(timestamp, glucose mg/dL)` points
+///
+/// Produced by [`ReadResponse::time_series`]; convenient for charting and analysis libraries.
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::TimeSeries;
+/// use chrono::{Duration, TimeZone, Utc};
+///
+/// let at = |minute| Utc.with_ymd_and_hms(2024, 1, 1, 12, minute, 0).unwrap();
+/// let series = TimeSeries::new(vec![(at(0), 100.0), (at(5), 110.0), (at(20), 130.0)]);
+///
+/// assert_eq!(series.values(), vec![100.0, 110.0, 130.0]);
+/// assert_eq!(series.window(at(5), at(20)).len(), 2);
+///
+/// let resampled = series.resample(Duration::minutes(15));
+/// assert_eq!(resampled.points(), &[(at(0), 105.0), (at(15), 130.0)]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TimeSeries(Vec<(DateTime<Utc>, f64)>);
+
+impl TimeSeries {
+    /// Create a series from points, sorting them by timestamp
+    pub fn new(mut points: Vec<(DateTime<Utc>, f64)>) -> Self {
+        points.sort_by_key(|(date, _)| *date);
+        Self(points)
+    }
+
+    /// All `(timestamp, value)` points in order
+    pub fn points(&self) -> &[(DateTime<Utc>, f64)] {
+        &self.0
+    }
+
+    /// Glucose values in order
+    pub fn values(&self) -> Vec<f64> {
+        self.0.iter().map(|(_, value)| *value).collect()
+    }
+
+    /// Timestamps in order
+    pub fn timestamps(&self) -> Vec<DateTime<Utc>> {
+        self.0.iter().map(|(date, _)| *date).collect()
+    }
+
+    /// Number of points
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the series has no points
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Points with timestamps in `from..=to`
+    pub fn window(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> TimeSeries {
+        TimeSeries(
+            self.0
+                .iter()
+                .filter(|(date, _)| *date >= from && *date <= to)
+                .copied()
+                .collect(),
+        )
+    }
+
+    /// Average points into fixed `interval` buckets aligned to the Unix epoch
+    ///
+    /// Each output point is stamped with the start of its bucket; empty buckets are omitted.
+    /// A non-positive `interval` returns the series unchanged.
+    pub fn resample(&self, interval: Duration) -> TimeSeries {
+        let step = interval.num_seconds();
+        if step <= 0 {
+            return self.clone();
+        }
+
+        let mut buckets: Vec<(i64, f64, usize)> = Vec::new();
+        for (date, value) in &self.0 {
+            let bucket = date.timestamp().div_euclid(step) * step;
+            match buckets.last_mut() {
+                Some((start, sum, count)) if *start == bucket => {
+                    *sum += value;
+                    *count += 1;
+                }
+                _ => buckets.push((bucket, *value, 1)),
+            }
+        }
+
+        TimeSeries(
+            buckets
+                .into_iter()
+                .filter_map(|(start, sum, count)| {
+                    DateTime::from_timestamp(start, 0).map(|date| (date, sum / count as f64))
+                })
+                .collect(),
+        )
+    }
 }
 
 /// Response from the read_raw() method with unparsed API data
 ///
 /// Access to raw API responses for advanced use cases
 ///
+/// The graph endpoint only carries glucose readings. Treatment entries (carbs, insulin, notes)
+/// logged in the patient's LibreLink app are not shared with followers: neither the graph nor the
+/// [`get_logbook`](crate::LibreLinkUpClient::get_logbook) endpoint returns them, so there is no
+/// event data to expose here.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -112,3 +829,322 @@ pub struct ReadRawResponse {
     /// Graph data (historical glucose readings)
     pub graph_data: Vec<GlucoseItem>,
 }
+
+/// Number of most recent graph readings considered by [`ReadRawResponse::sensor_reliability`]
+/// (6 hours at the 15-minute graph interval)
+const RELIABILITY_WINDOW: usize = 24;
+
+/// Estimated trustworthiness of a sensor's recent readings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Reliability {
+    /// Fewer than 10% of recent readings are flagged
+    Good,
+    /// 10% to 30% of recent readings are flagged
+    Degraded,
+    /// More than 30% of recent readings are flagged; consider replacing the sensor
+    Failing,
+}
+
+impl ReadRawResponse {
+    /// Estimate sensor reliability from the `measurement_color` of recent readings
+    ///
+    /// Looks at the last 24 graph readings plus the current measurement. A reading is flagged
+    /// when its [`MeasurementColor`] is `Unknown`, i.e. not one of the four glucose range colors
+    /// the app displays, which is how the API marks values it could not classify. This is only available on the raw
+    /// response because [`ReadResponse`] does not keep the measurement color.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use libre_link_up_api_client::{LibreLinkUpClient, Reliability};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = LibreLinkUpClient::simple("email@example.com".to_string(), "password".to_string(), None)?;
+    /// let raw = client.read_raw().await?;
+    /// if raw.sensor_reliability() == Reliability::Failing {
+    ///     println!("Sensor readings look unreliable; consider replacing it");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sensor_reliability(&self) -> Reliability {
+        let start = self.graph_data.len().saturating_sub(RELIABILITY_WINDOW);
+        let colors: Vec<MeasurementColor> = self.graph_data[start..]
+            .iter()
+            .map(GlucoseItem::color)
+            .chain(std::iter::once(self.connection.glucose_measurement.color()))
+            .collect();
+        let flagged = colors
+            .iter()
+            .filter(|color| matches!(color, MeasurementColor::Unknown(_)))
+            .count();
+        let fraction = flagged as f64 / colors.len() as f64;
+
+        if fraction < 0.1 {
+            Reliability::Good
+        } else if fraction <= 0.3 {
+            Reliability::Degraded
+        } else {
+            Reliability::Failing
+        }
+    }
+
+    /// Like [`ReadResponse::stats`], against the connection's `target_low`/`target_high`
+    ///
+    /// Falls back to the consensus 70–180 mg/dL range if the connection has no valid target
+    /// range. Graph readings with an unparseable timestamp are left out, as in
+    /// [`read`](crate::LibreLinkUpClient::read).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use libre_link_up_api_client::LibreLinkUpClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = LibreLinkUpClient::simple("email@example.com".to_string(), "password".to_string(), None)?;
+    /// let stats = client.read_raw().await?.stats();
+    /// println!("{:.0}% in the patient's target range", stats.time_in_range);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stats(&self) -> GlucoseStats {
+        let TargetRange { low, high, .. } =
+            TargetRange::from_connection(&self.connection).unwrap_or_default();
+        let readings: Vec<LibreCgmData> = self
+            .graph_data
+            .iter()
+            .filter_map(|item| try_map_glucose_data(item).ok())
+            .collect();
+        GlucoseStats::from_readings(&readings, low, high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(deprecated)]
+    fn reading(mgdl: f64) -> LibreCgmData {
+        LibreCgmData {
+            value: mgdl,
+            mgdl,
+            mmol: mgdl / 18.0182,
+            is_high: false,
+            is_low: true,
+            trend: TrendType::Flat,
+            date: Utc::now(),
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn readings_saved_before_unit_fields_still_load() {
+        let old = r#"{"value":112.0,"isHigh":false,"isLow":false,"trend":"Flat","date":"2023-11-14T10:15:00Z"}"#;
+        let reading: LibreCgmData = serde_json::from_str(old).unwrap();
+        assert_eq!(reading.value, 112.0);
+        assert_eq!((reading.mgdl, reading.mmol), (0.0, 0.0));
+        assert_eq!(reading.trend, TrendType::Flat);
+
+        let saved = serde_json::to_string(&reading).unwrap();
+        assert_eq!(
+            serde_json::from_str::<LibreCgmData>(&saved).unwrap(),
+            reading
+        );
+    }
+
+    fn raw_with_colors(flagged: usize, total: usize) -> ReadRawResponse {
+        let connection: Connection =
+            serde_json::from_str(include_str!("../../tests/fixtures/connection.json")).unwrap();
+        let graph_data = (0..total)
+            .map(|i| {
+                let mut item = connection.glucose_item.clone();
+                item.measurement_color = if i < flagged { 0 } else { 1 };
+                item
+            })
+            .collect();
+        ReadRawResponse {
+            connection,
+            active_sensors: Vec::new(),
+            graph_data,
+        }
+    }
+
+    #[test]
+    fn trend_strategies_disagree_on_oscillating_readings() {
+        use TrendType::*;
+        let readings: Vec<LibreCgmData> = [SingleUp, SingleDown, SingleUp, SingleDown, Flat]
+            .into_iter()
+            .map(|trend| LibreCgmData {
+                trend,
+                ..reading(100.0)
+            })
+            .collect();
+        let (oscillating, latest) = (&readings[..4], SingleDown);
+        assert_eq!(
+            TrendStrategy::MostRecent.combine(oscillating, latest),
+            SingleDown
+        );
+        assert_eq!(
+            TrendStrategy::Majority.combine(oscillating, latest),
+            SingleDown
+        );
+        assert_eq!(TrendStrategy::Averaged.combine(oscillating, latest), Flat);
+        assert_eq!(
+            TrendStrategy::Majority.combine(&readings[1..], Flat),
+            SingleDown
+        );
+        assert_eq!(TrendStrategy::Majority.combine(&[], Flat), Flat);
+    }
+
+    #[test]
+    fn classification_bounds_are_inclusive_in_range() {
+        use GlucoseClassification::*;
+        let classes: Vec<_> = [53.9, 54.0, 69.9, 70.0, 180.0, 180.1, 250.0, 250.1]
+            .map(|mgdl| reading(mgdl).classify(70.0, 180.0, 54.0, 250.0))
+            .into();
+        assert_eq!(
+            classes,
+            [
+                UrgentLow, Low, Low, InRange, InRange, High, High, UrgentHigh
+            ]
+        );
+        assert_eq!(
+            reading(63.0).classify_in(GlucoseUnit::MmolL, 3.9, 10.0, 3.0, 13.9),
+            Low
+        );
+    }
+
+    #[test]
+    fn csv_rows_follow_the_time_series_order() {
+        let at = |minute: i64| DateTime::from_timestamp(1_700_000_000 + minute * 60, 0).unwrap();
+        let mut late = reading(180.0);
+        late.date = at(15);
+        late.mmol = 10.0;
+        late.trend = TrendType::SingleUp;
+        late.is_high = true;
+        late.is_low = false;
+        let mut early = reading(55.5);
+        early.date = at(0);
+        early.mmol = 3.1;
+        let mut current = reading(120.0);
+        current.date = at(10);
+
+        let response = ReadResponse {
+            current,
+            history: vec![late, early],
+            target: None,
+            unit: GlucoseUnit::MgDl,
+        };
+        let csv = response.to_csv();
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            rows,
+            [
+                "date,mgdl,mmol,trend,is_high,is_low",
+                "2023-11-14T22:13:20Z,55.5,3.1,Flat,false,true",
+                "2023-11-14T22:28:20Z,180,10,SingleUp,true,false",
+            ]
+        );
+    }
+
+    #[test]
+    fn trend_indices_round_trip() {
+        for trend in TREND_MAP {
+            assert_eq!(TrendType::try_from(i32::from(trend)).unwrap(), trend);
+        }
+        assert!(TrendType::try_from(-1).is_err());
+    }
+
+    #[test]
+    fn sensor_reliability_thresholds() {
+        // 24 graph readings plus the (unflagged) current measurement
+        assert_eq!(
+            raw_with_colors(0, 24).sensor_reliability(),
+            Reliability::Good
+        );
+        assert_eq!(
+            raw_with_colors(2, 24).sensor_reliability(),
+            Reliability::Good
+        );
+        assert_eq!(
+            raw_with_colors(3, 24).sensor_reliability(),
+            Reliability::Degraded
+        );
+        assert_eq!(
+            raw_with_colors(7, 24).sensor_reliability(),
+            Reliability::Degraded
+        );
+        assert_eq!(
+            raw_with_colors(8, 24).sensor_reliability(),
+            Reliability::Failing
+        );
+        assert_eq!(
+            raw_with_colors(0, 0).sensor_reliability(),
+            Reliability::Good
+        );
+    }
+
+    #[test]
+    fn sensor_reliability_ignores_older_readings() {
+        // The 10 flagged readings are older than the 24-reading window
+        assert_eq!(
+            raw_with_colors(10, 34).sensor_reliability(),
+            Reliability::Good
+        );
+    }
+
+    #[test]
+    fn time_weighted_average_diverges_from_mean_on_irregular_spacing() {
+        let start = Utc::now();
+        let at = |minutes: i64, mgdl: f64| LibreCgmData {
+            date: start + Duration::minutes(minutes),
+            ..reading(mgdl)
+        };
+        // Two hours at 100 mg/dL sampled every 15 minutes, then a dense burst of highs at
+        // one-minute spacing
+        let mut history: Vec<LibreCgmData> = (0..=8).map(|i| at(15 * i, 100.0)).collect();
+        history.extend((1..=5).map(|i| at(120 + i, 200.0)));
+        let response = ReadResponse {
+            current: at(126, 200.0),
+            history,
+            target: None,
+            unit: GlucoseUnit::MgDl,
+        };
+
+        let values = response.time_series().values();
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let weighted = response.time_weighted_average().unwrap();
+
+        assert!((mean - 140.0).abs() < 1e-9);
+        // 120 min at 100, 1 min ramp (150 avg), 5 min at 200: (12000 + 150 + 1000) / 126
+        assert!((weighted - 13150.0 / 126.0).abs() < 1e-9);
+        assert!(mean - weighted > 30.0);
+    }
+
+    #[test]
+    fn time_weighted_average_of_single_reading() {
+        let response = ReadResponse {
+            current: reading(120.0),
+            history: Vec::new(),
+            target: None,
+            unit: GlucoseUnit::MgDl,
+        };
+        assert_eq!(response.time_weighted_average(), Some(120.0));
+    }
+
+    #[test]
+    fn urgent_low_is_strictly_below_fixed_threshold() {
+        let connection: Connection =
+            serde_json::from_str(include_str!("../../tests/fixtures/connection.json")).unwrap();
+        let mut device = connection.patient_device;
+        device.fixed_low_alarm_values.as_mut().unwrap().mgdl = 55.0;
+
+        assert_eq!(reading(54.0).is_urgent_low(&device), Some(true));
+        assert_eq!(reading(54.9).is_urgent_low(&device), Some(true));
+        assert_eq!(reading(55.0).is_urgent_low(&device), Some(false));
+        assert_eq!(reading(56.0).is_urgent_low(&device), Some(false));
+
+        device.fixed_low_alarm_values = None;
+        assert_eq!(reading(40.0).is_urgent_low(&device), None);
+    }
+}
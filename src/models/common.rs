@@ -1,37 +1,267 @@
 //! Common data structures shared across multiple API endpoints
 
+use chrono::{DateTime, Duration, FixedOffset, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Adds `parsed_timestamp` and `parsed_local_timestamp` to a model with `factory_timestamp`
+/// and `timestamp` fields
+macro_rules! impl_parsed_timestamps {
+    ($ty:ty, $what:literal) => {
+        impl $ty {
+            #[doc = concat!("Time of the ", $what, ", parsed from `FactoryTimestamp` (the sensor's UTC clock)")]
+            ///
+            /// The API sends [`API_TIMESTAMP_FORMAT`](crate::utils::API_TIMESTAMP_FORMAT)
+            /// (`"%m/%d/%Y %I:%M:%S %p"`, e.g. `"11/14/2023 10:15:00 AM"`); see
+            /// [`parse_timestamp`](crate::utils::parse_timestamp) for the other accepted forms.
+            /// Returns `None` if the timestamp cannot be parsed.
+            pub fn parsed_timestamp(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+                $crate::utils::parse_timestamp(&self.factory_timestamp)
+            }
+
+            #[doc = concat!("Phone wall-clock time of the ", $what, ", parsed from `Timestamp`")]
+            ///
+            /// Same formats as [`parsed_timestamp`](Self::parsed_timestamp), but the value is in
+            /// the phone's local timezone, so it is returned without one; an explicit offset in
+            /// the timestamp is dropped, not applied. Returns `None` if the timestamp cannot be
+            /// parsed.
+            pub fn parsed_local_timestamp(&self) -> Option<chrono::NaiveDateTime> {
+                $crate::utils::parse_local_timestamp(&self.timestamp)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_parsed_timestamps;
 
 /// Authentication ticket with token and expiration information
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AuthTicket {
     /// JWT authentication token
     pub token: String,
-    /// Unix timestamp when the token expires
+    /// Unix timestamp when the token expires, as sent (see [`expires_at`](Self::expires_at))
     #[serde(default)]
     pub expires: i64,
-    /// Duration in seconds for which the token is valid
+    /// Duration for which the token is valid, as sent (milliseconds in observed responses)
     #[serde(default)]
     pub duration: i64,
 }
 
+impl AuthTicket {
+    /// Expiry of the token, or `None` if the server left `expires` unset (`0`)
+    ///
+    /// `expires` is Unix time in seconds in observed responses. Values too large to be seconds
+    /// (after the year 5000) are taken to be milliseconds, so either form yields the same time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libre_link_up_api_client::models::AuthTicket;
+    ///
+    /// let ticket = |expires| AuthTicket { token: String::new(), expires, duration: 0 };
+    /// assert_eq!(ticket(1_700_000_000).expires_at().unwrap().timestamp(), 1_700_000_000);
+    /// assert_eq!(ticket(1_700_000_000_000).expires_at(), ticket(1_700_000_000).expires_at());
+    /// assert_eq!(ticket(0).expires_at(), None);
+    /// ```
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        const MAX_SECONDS: i64 = 100_000_000_000;
+        match self.expires {
+            ..=0 => None,
+            seconds @ ..MAX_SECONDS => DateTime::from_timestamp(seconds, 0),
+            millis => DateTime::from_timestamp_millis(millis),
+        }
+    }
+}
+
+/// Assumed sensor lifetime, used by [`Sensor::expires_at`]
+///
+/// FreeStyle Libre 2 and 3 sensors last 14 days. The API's sensor type ([`Sensor::pt`]) is not
+/// mapped to lifetimes, so sensors with a different lifetime (e.g. the 15-day Libre 3 Plus)
+/// should use [`Sensor::expires_after`] instead.
+pub const SENSOR_LIFETIME_DAYS: i64 = 14;
+
 /// Sensor device information
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Sensor {
     /// Device identifier
     #[serde(rename = "deviceId")]
     pub device_id: String,
     /// Serial number
     pub sn: String,
-    /// Sensor age (days)
+    /// Activation time (Unix seconds); see [`activated_at`](Self::activated_at)
+    #[serde(deserialize_with = "lenient::int")]
     pub a: i32,
     /// Sensor warmup period (minutes)
+    #[serde(deserialize_with = "lenient::int")]
     pub w: i32,
     /// Sensor type
+    #[serde(deserialize_with = "lenient::int")]
     pub pt: i32,
 }
 
+/// Deserializers that accept the different JSON shapes endpoints use for the same field.
+///
+/// `/connections` sends alarm-rule flags as booleans and counts as integers, while `/graph` and
+/// the notification settings may send `null` flags or float-encoded numbers. Sharing these
+/// helpers lets one set of model types parse every variant.
+///
+/// The sensor, device, and alarm-rule types also fill missing fields with their defaults, so a
+/// field Abbott drops from these nested settings does not fail the whole read.
+pub(crate) mod lenient {
+    use serde::{Deserialize, Deserializer, de::Error};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum BoolLike {
+        Bool(bool),
+        Int(i64),
+        Float(f64),
+    }
+
+    impl BoolLike {
+        fn into_bool(self) -> bool {
+            match self {
+                BoolLike::Bool(b) => b,
+                BoolLike::Int(i) => i != 0,
+                BoolLike::Float(f) => f != 0.0,
+            }
+        }
+    }
+
+    /// `bool`, `0`/`1`, or `null` (as `None`)
+    pub(crate) fn opt_bool<'de, D: Deserializer<'de>>(d: D) -> Result<Option<bool>, D::Error> {
+        Ok(Option::<BoolLike>::deserialize(d)?.map(BoolLike::into_bool))
+    }
+
+    /// `bool`, `0`/`1`, or `null` (as `false`)
+    pub(crate) fn bool<'de, D: Deserializer<'de>>(d: D) -> Result<bool, D::Error> {
+        Ok(opt_bool(d)?.unwrap_or(false))
+    }
+
+    /// Integer, or a float with no fractional part
+    pub(crate) fn int<'de, D: Deserializer<'de>>(d: D) -> Result<i32, D::Error> {
+        let value = f64::deserialize(d)?;
+        if value.fract() != 0.0 || value < i32::MIN as f64 || value > i32::MAX as f64 {
+            return Err(D::Error::custom(format!(
+                "expected an integer, found {}",
+                value
+            )));
+        }
+        Ok(value as i32)
+    }
+}
+
+impl Sensor {
+    /// When the sensor was activated, or `None` if `a` is not a valid timestamp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libre_link_up_api_client::models::Sensor;
+    ///
+    /// let sensor = Sensor {
+    ///     device_id: String::new(),
+    ///     sn: "0M0008B8CT".to_string(),
+    ///     a: 1_700_000_000,
+    ///     w: 60,
+    ///     pt: 4,
+    /// };
+    /// assert_eq!(sensor.activated_at().unwrap().to_rfc3339(), "2023-11-14T22:13:20+00:00");
+    /// ```
+    pub fn activated_at(&self) -> Option<DateTime<Utc>> {
+        if self.a <= 0 {
+            return None;
+        }
+        DateTime::from_timestamp(i64::from(self.a), 0)
+    }
+
+    /// The serial number trimmed, uppercased, and grouped in fours for display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libre_link_up_api_client::models::Sensor;
+    ///
+    /// let sensor = Sensor {
+    ///     device_id: String::new(),
+    ///     sn: " 0m0008b8ct".to_string(),
+    ///     a: 0,
+    ///     w: 60,
+    ///     pt: 4,
+    /// };
+    /// assert_eq!(sensor.serial_pretty(), "0M00 08B8 CT");
+    /// ```
+    pub fn serial_pretty(&self) -> String {
+        let serial: Vec<char> = self.sn.trim().to_uppercase().chars().collect();
+        serial
+            .chunks(4)
+            .map(|group| group.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Full days since activation, or `None` if the activation time is unknown
+    pub fn age_days(&self) -> Option<i64> {
+        self.age_days_at(Utc::now())
+    }
+
+    /// Like [`age_days`](Self::age_days), but relative to `now`
+    pub fn age_days_at(&self, now: DateTime<Utc>) -> Option<i64> {
+        Some((now - self.activated_at()?).num_days())
+    }
+
+    /// When warmup ends, [`w`](Self::w) minutes after activation
+    fn ready_at(&self) -> Option<DateTime<Utc>> {
+        Some(self.activated_at()? + Duration::minutes(i64::from(self.w)))
+    }
+
+    /// Whether the sensor is still warming up; `false` if the activation time is unknown
+    pub fn is_warming_up(&self) -> bool {
+        self.is_warming_up_at(Utc::now())
+    }
+
+    /// Like [`is_warming_up`](Self::is_warming_up), but relative to `now`
+    pub fn is_warming_up_at(&self, now: DateTime<Utc>) -> bool {
+        self.ready_at().is_some_and(|ready| ready > now)
+    }
+
+    /// When the sensor ends, assuming the [`SENSOR_LIFETIME_DAYS`] lifetime
+    ///
+    /// Returns `None` if the activation time is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Duration, Utc};
+    /// use libre_link_up_api_client::models::Sensor;
+    ///
+    /// let sensor = Sensor {
+    ///     device_id: String::new(),
+    ///     sn: "0M0008B8CT".to_string(),
+    ///     a: 1_700_000_000,
+    ///     w: 60,
+    ///     pt: 4,
+    /// };
+    /// let expires = sensor.expires_at().unwrap();
+    /// assert_eq!(expires.to_rfc3339(), "2023-11-28T22:13:20+00:00");
+    /// if expires - Utc::now() < Duration::days(1) {
+    ///     println!("Sensor ends within a day");
+    /// }
+    /// ```
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_after(Duration::days(SENSOR_LIFETIME_DAYS))
+    }
+
+    /// When the sensor ends, given its `lifetime` from activation
+    pub fn expires_after(&self, lifetime: Duration) -> Option<DateTime<Utc>> {
+        Some(self.activated_at()? + lifetime)
+    }
+}
+
 /// Fixed low alarm threshold values in both units
+///
+/// Both values are required: a safety threshold is never filled in with a default.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FixedLowAlarmValues {
     /// Threshold in mg/dL
@@ -41,11 +271,13 @@ pub struct FixedLowAlarmValues {
 }
 
 /// Patient device configuration
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PatientDevice {
     /// Device identifier
     pub did: String,
     /// Device type identifier
+    #[serde(deserialize_with = "lenient::int")]
     pub dtid: i32,
     /// Device version
     pub v: String,
@@ -54,22 +286,35 @@ pub struct PatientDevice {
     /// High limit threshold
     pub hl: f64,
     /// Unit of measure
+    #[serde(deserialize_with = "lenient::int")]
     pub u: i32,
-    /// Fixed low alarm values
+    /// Fixed low alarm values (`None` if the API did not send them)
     #[serde(rename = "fixedLowAlarmValues")]
-    pub fixed_low_alarm_values: FixedLowAlarmValues,
+    pub fixed_low_alarm_values: Option<FixedLowAlarmValues>,
     /// Whether alarms are enabled
+    #[serde(deserialize_with = "lenient::bool")]
     pub alarms: bool,
+    /// Low limit enabled (only sent with notification settings)
+    #[serde(deserialize_with = "lenient::opt_bool")]
+    pub l: Option<bool>,
+    /// High limit enabled (only sent with notification settings)
+    #[serde(deserialize_with = "lenient::opt_bool")]
+    pub h: Option<bool>,
+    /// Fixed low threshold (only sent with notification settings)
+    #[serde(rename = "fixedLowThreshold")]
+    pub fixed_low_threshold: Option<f64>,
 }
 
 /// Alarm rule configuration for falling glucose (F)
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct F {
     /// Threshold high
     pub th: f64,
     /// Threshold high in mmol/L
     pub thmm: f64,
     /// Duration
+    #[serde(deserialize_with = "lenient::int")]
     pub d: i32,
     /// Threshold low
     pub tl: f64,
@@ -78,13 +323,18 @@ pub struct F {
 }
 
 /// Alarm rule configuration for low glucose (L)
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct L {
-    /// Threshold high
-    pub th: f64,
-    /// Threshold high in mmol/L
-    pub thmm: f64,
+    /// Whether this alarm rule is enabled (only sent with notification settings)
+    #[serde(deserialize_with = "lenient::opt_bool")]
+    pub on: Option<bool>,
+    /// Alarm threshold (`None` if the API did not send it)
+    pub th: Option<f64>,
+    /// Alarm threshold in mmol/L (`None` if the API did not send it)
+    pub thmm: Option<f64>,
     /// Duration
+    #[serde(deserialize_with = "lenient::int")]
     pub d: i32,
     /// Threshold low
     pub tl: f64,
@@ -93,39 +343,51 @@ pub struct L {
 }
 
 /// Alarm rule configuration for high glucose (H)
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct H {
     /// Whether this alarm rule is enabled
+    #[serde(deserialize_with = "lenient::bool")]
     pub on: bool,
-    /// Threshold high
-    pub th: f64,
-    /// Threshold high in mmol/L
-    pub thmm: f64,
+    /// Alarm threshold (`None` if the API did not send it)
+    pub th: Option<f64>,
+    /// Alarm threshold in mmol/L (`None` if the API did not send it)
+    pub thmm: Option<f64>,
     /// Duration
+    #[serde(deserialize_with = "lenient::int")]
     pub d: i32,
     /// Frequency
     pub f: f64,
 }
 
 /// Alarm rule configuration for no data (Nd)
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Nd {
+    /// Whether this alarm rule is enabled (only sent with notification settings)
+    #[serde(deserialize_with = "lenient::opt_bool")]
+    pub on: Option<bool>,
     /// Interval
+    #[serde(deserialize_with = "lenient::int")]
     pub i: i32,
     /// Repeat
+    #[serde(deserialize_with = "lenient::int")]
     pub r: i32,
     /// Limit
+    #[serde(deserialize_with = "lenient::int")]
     pub l: i32,
 }
 
 /// Standard alarm rule configuration
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Std {}
 
 /// Alarm rules configuration
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AlarmRules {
     /// Critical alarm enabled
+    #[serde(deserialize_with = "lenient::opt_bool")]
     pub c: Option<bool>,
     /// High glucose alarm configuration
     pub h: H,
@@ -136,8 +398,10 @@ pub struct AlarmRules {
     /// No data alarm configuration
     pub nd: Nd,
     /// Period
+    #[serde(deserialize_with = "lenient::int")]
     pub p: i32,
     /// Repeat
+    #[serde(deserialize_with = "lenient::int")]
     pub r: i32,
     /// Standard alarm configuration
     pub std: Std,
@@ -164,13 +428,13 @@ pub struct GlucoseItem {
     /// Trend message (if available)
     #[serde(rename = "TrendMessage")]
     pub trend_message: Option<serde_json::Value>,
-    /// Measurement color indicator
+    /// Measurement color indicator, see [`color`](Self::color)
     #[serde(rename = "MeasurementColor")]
     pub measurement_color: i32,
-    /// Glucose units (0 = mg/dL, 1 = mmol/L)
+    /// Unit of `value` (0 = mmol/L, 1 = mg/dL)
     #[serde(rename = "GlucoseUnits")]
     pub glucose_units: i32,
-    /// Glucose value in configured units
+    /// Glucose value in the unit given by `glucose_units`
     #[serde(rename = "Value")]
     pub value: f64,
     /// Whether the value is above target high
@@ -181,6 +445,98 @@ pub struct GlucoseItem {
     pub is_low: bool,
 }
 
+impl GlucoseItem {
+    /// Color the app shows for the reading
+    pub fn color(&self) -> MeasurementColor {
+        MeasurementColor::from(self.measurement_color)
+    }
+
+    /// Clock drift between the phone (`Timestamp`) and the sensor (`FactoryTimestamp`)
+    ///
+    /// `FactoryTimestamp` comes from the sensor and is always UTC, so it is the one to trust
+    /// for ordering and charting. `Timestamp` is the phone's local wall-clock time, so it also
+    /// includes the phone's UTC offset at the time of the reading, which the API does not
+    /// report; pass it as `utc_offset` and it is removed, leaving only the drift. A positive
+    /// value means the phone clock is ahead.
+    ///
+    /// Returns `None` if either timestamp cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Duration, FixedOffset};
+    /// # use libre_link_up_api_client::models::GlucoseItem;
+    /// # let mut item: GlucoseItem = serde_json::from_value(serde_json::json!({
+    /// #     "FactoryTimestamp": "", "Timestamp": "", "type": 0, "ValueInMgPerDl": 100.0,
+    /// #     "MeasurementColor": 1, "GlucoseUnits": 1, "Value": 100.0, "isHigh": false, "isLow": false
+    /// # })).unwrap();
+    /// item.factory_timestamp = "11/14/2023 10:15:00 AM".to_string();
+    /// item.timestamp = "11/14/2023 11:25:00 AM".to_string();
+    ///
+    /// // A phone in UTC+1 whose clock is 10 minutes fast
+    /// let cet = FixedOffset::east_opt(3600).unwrap();
+    /// assert_eq!(item.clock_drift(cet), Some(Duration::minutes(10)));
+    /// ```
+    pub fn clock_drift(&self, utc_offset: FixedOffset) -> Option<Duration> {
+        let factory = self.parsed_timestamp()?;
+        let phone = self.parsed_local_timestamp()?;
+        Some(phone - factory.with_timezone(&utc_offset).naive_local())
+    }
+}
+
+/// Color of the dot the app draws for a reading, from its `MeasurementColor`
+///
+/// The codes follow what the app renders for them; any other value, which the API uses for
+/// readings it could not classify, is kept as [`Unknown`](Self::Unknown).
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::models::MeasurementColor;
+///
+/// assert_eq!(MeasurementColor::from(1), MeasurementColor::InRange);
+/// assert_eq!(MeasurementColor::from(0), MeasurementColor::Unknown(0));
+/// assert_eq!(i32::from(MeasurementColor::Low), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum MeasurementColor {
+    /// Green: within the target range (`1`)
+    InRange,
+    /// Yellow: above the target range (`2`)
+    AboveTarget,
+    /// Orange: above the high alarm threshold (`3`)
+    High,
+    /// Red: below the low alarm threshold (`4`)
+    Low,
+    /// Any other code
+    Unknown(i32),
+}
+
+impl From<i32> for MeasurementColor {
+    fn from(code: i32) -> Self {
+        match code {
+            1 => MeasurementColor::InRange,
+            2 => MeasurementColor::AboveTarget,
+            3 => MeasurementColor::High,
+            4 => MeasurementColor::Low,
+            other => MeasurementColor::Unknown(other),
+        }
+    }
+}
+
+impl From<MeasurementColor> for i32 {
+    fn from(color: MeasurementColor) -> Self {
+        match color {
+            MeasurementColor::InRange => 1,
+            MeasurementColor::AboveTarget => 2,
+            MeasurementColor::High => 3,
+            MeasurementColor::Low => 4,
+            MeasurementColor::Unknown(code) => code,
+        }
+    }
+}
+
 /// Glucose measurement with required trend arrow
 ///
 /// Extends `GlucoseItem` but requires `TrendArrow` to be present (not optional).
@@ -205,13 +561,13 @@ pub struct GlucoseMeasurement {
     /// Trend message (if available)
     #[serde(rename = "TrendMessage")]
     pub trend_message: Option<serde_json::Value>,
-    /// Measurement color indicator
+    /// Measurement color indicator, see [`color`](Self::color)
     #[serde(rename = "MeasurementColor")]
     pub measurement_color: i32,
-    /// Glucose units (0 = mg/dL, 1 = mmol/L)
+    /// Unit of `value` (0 = mmol/L, 1 = mg/dL)
     #[serde(rename = "GlucoseUnits")]
     pub glucose_units: i32,
-    /// Glucose value in configured units
+    /// Glucose value in the unit given by `glucose_units`
     #[serde(rename = "Value")]
     pub value: f64,
     /// Whether the value is above target high
@@ -222,6 +578,16 @@ pub struct GlucoseMeasurement {
     pub is_low: bool,
 }
 
+impl GlucoseMeasurement {
+    /// Color the app shows for the reading
+    pub fn color(&self) -> MeasurementColor {
+        MeasurementColor::from(self.measurement_color)
+    }
+}
+
+impl_parsed_timestamps!(GlucoseItem, "reading");
+impl_parsed_timestamps!(GlucoseMeasurement, "reading");
+
 /// Active sensor with associated device
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ActiveSensor {
@@ -231,7 +597,44 @@ pub struct ActiveSensor {
     pub device: PatientDevice,
 }
 
+impl ActiveSensor {
+    /// Time left until the sensor finishes warming up, or `None` once it is ready
+    ///
+    /// Warmup ends [`w`](Sensor::w) minutes after [`activated_at`](Sensor::activated_at). Also
+    /// returns `None` if the activation time is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use libre_link_up_api_client::LibreLinkUpClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = LibreLinkUpClient::simple("email@example.com".to_string(), "password".to_string(), None)?;
+    /// for active in client.read_raw().await?.active_sensors {
+    ///     if let Some(remaining) = active.warmup_remaining() {
+    ///         println!("Sensor ready in {} minutes", remaining.num_minutes());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn warmup_remaining(&self) -> Option<Duration> {
+        self.warmup_remaining_at(Utc::now())
+    }
+
+    /// Like [`warmup_remaining`](Self::warmup_remaining), but relative to `now`
+    pub fn warmup_remaining_at(&self, now: DateTime<Utc>) -> Option<Duration> {
+        let ready = self.sensor.ready_at()?;
+        (ready > now).then(|| ready - now)
+    }
+}
+
 /// Connection information for a patient
+///
+/// The connections list ([`ConnectionsResponse`](crate::models::connections::ConnectionsResponse)),
+/// the graph ([`GraphData`](crate::models::graph::GraphData)), and the single-connection
+/// response all deserialize into this one type, so a listed connection can be passed anywhere a
+/// graph connection is expected.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Connection {
     /// Connection identifier
@@ -255,7 +658,8 @@ pub struct Connection {
     /// Target high glucose value
     #[serde(rename = "targetHigh")]
     pub target_high: f64,
-    /// Unit of measure
+    /// Preferred unit of measure (0 = mmol/L, 1 = mg/dL); individual readings carry their own
+    /// `glucose_units`
     pub uom: i32,
     /// Sensor information
     pub sensor: Sensor,
@@ -269,11 +673,392 @@ pub struct Connection {
     #[serde(rename = "glucoseItem")]
     pub glucose_item: GlucoseItem,
     /// Glucose alarm information (if any)
+    ///
+    /// Read-only: the LibreLinkUp API has no known endpoint for acknowledging or dismissing an
+    /// alarm, so alarms cleared in the app are not reflected here and cannot be cleared through
+    /// this client.
     #[serde(rename = "glucoseAlarm")]
-    pub glucose_alarm: Option<serde_json::Value>,
+    pub glucose_alarm: Option<GlucoseAlarm>,
     /// Patient device information
     #[serde(rename = "patientDevice")]
     pub patient_device: PatientDevice,
     /// Creation timestamp
     pub created: i64,
 }
+
+impl Connection {
+    /// Stable key identifying this connection's patient
+    ///
+    /// `patient_id` is the identifier that stays the same across requests and re-invites
+    /// (the connection `id` may change), so it is the one to key per-patient caches on.
+    pub fn key(&self) -> PatientKey {
+        PatientKey(self.patient_id.clone())
+    }
+}
+
+/// Active glucose alarm of a [`Connection`]
+///
+/// The API sends the same integer as [`LogbookEntry::alarm_type`](crate::LogbookEntry::alarm_type),
+/// either on its own or as the `alarmType` field of an object. Integers outside the known codes
+/// and payloads of any other shape are kept as [`Unknown`](Self::Unknown) instead of failing
+/// the whole response, and serialize back unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::models::GlucoseAlarm;
+///
+/// let alarm: GlucoseAlarm = serde_json::from_str(r#"{"alarmType": 2}"#).unwrap();
+/// assert_eq!(alarm, GlucoseAlarm::UrgentLow);
+/// assert!(alarm.is_low());
+///
+/// let alarm: GlucoseAlarm = serde_json::from_str("7").unwrap();
+/// assert_eq!(alarm, GlucoseAlarm::Unknown(serde_json::json!(7)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GlucoseAlarm {
+    /// Below the low alarm threshold (`0`)
+    Low,
+    /// Above the high alarm threshold (`1`)
+    High,
+    /// Below the fixed urgent low threshold (`2`)
+    UrgentLow,
+    /// Unrecognised alarm payload, as sent
+    Unknown(serde_json::Value),
+}
+
+impl GlucoseAlarm {
+    /// API alarm code, or `None` for [`Unknown`](Self::Unknown)
+    pub const fn code(&self) -> Option<i32> {
+        match self {
+            GlucoseAlarm::Low => Some(0),
+            GlucoseAlarm::High => Some(1),
+            GlucoseAlarm::UrgentLow => Some(2),
+            GlucoseAlarm::Unknown(_) => None,
+        }
+    }
+
+    /// Whether this is a low or urgent low alarm
+    pub const fn is_low(&self) -> bool {
+        matches!(self, GlucoseAlarm::Low | GlucoseAlarm::UrgentLow)
+    }
+
+    /// Whether this is a high alarm
+    pub const fn is_high(&self) -> bool {
+        matches!(self, GlucoseAlarm::High)
+    }
+}
+
+impl From<serde_json::Value> for GlucoseAlarm {
+    fn from(value: serde_json::Value) -> Self {
+        let code = value
+            .get("alarmType")
+            .unwrap_or(&value)
+            .as_f64()
+            .filter(|code| code.fract() == 0.0);
+        match code {
+            Some(0.0) => GlucoseAlarm::Low,
+            Some(1.0) => GlucoseAlarm::High,
+            Some(2.0) => GlucoseAlarm::UrgentLow,
+            _ => GlucoseAlarm::Unknown(value),
+        }
+    }
+}
+
+impl Serialize for GlucoseAlarm {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            GlucoseAlarm::Unknown(value) => value.serialize(serializer),
+            known => known.code().serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GlucoseAlarm {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_json::Value::deserialize(deserializer).map(GlucoseAlarm::from)
+    }
+}
+
+/// Typed map key for a followed patient, derived from [`Connection::patient_id`]
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::models::PatientKey;
+/// use std::collections::HashMap;
+///
+/// let mut latest: HashMap<PatientKey, f64> = HashMap::new();
+/// latest.insert(PatientKey::from("patient-id"), 112.0);
+/// assert_eq!(latest[&PatientKey::from("patient-id")], 112.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PatientKey(pub String);
+
+impl PatientKey {
+    /// The underlying patient ID
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for PatientKey {
+    fn from(patient_id: &str) -> Self {
+        Self(patient_id.to_string())
+    }
+}
+
+impl From<String> for PatientKey {
+    fn from(patient_id: String) -> Self {
+        Self(patient_id)
+    }
+}
+
+impl fmt::Display for PatientKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for PatientKey {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glucose_item() -> GlucoseItem {
+        let connection: Connection =
+            serde_json::from_str(include_str!("../../tests/fixtures/connection.json")).unwrap();
+        connection.glucose_item
+    }
+
+    #[test]
+    fn timestamps_parse_in_the_api_format() {
+        let mut item = glucose_item();
+        assert_eq!(
+            item.parsed_timestamp().unwrap().to_rfc3339(),
+            "2023-11-14T10:15:00+00:00"
+        );
+        assert_eq!(
+            item.parsed_local_timestamp().unwrap().to_string(),
+            "2023-11-14 11:15:00"
+        );
+
+        item.factory_timestamp = "14/11/2023 10:15:00".to_string();
+        assert_eq!(item.parsed_timestamp(), None);
+    }
+
+    #[test]
+    fn local_timestamps_keep_their_wall_clock_time() {
+        let mut item = glucose_item();
+        item.factory_timestamp = "2023-11-14T10:15:00Z".to_string();
+        item.timestamp = "2023-11-14T11:25:00+01:00".to_string();
+        assert_eq!(
+            item.parsed_local_timestamp().unwrap().to_string(),
+            "2023-11-14 11:25:00"
+        );
+
+        let cet = FixedOffset::east_opt(3600).unwrap();
+        assert_eq!(item.clock_drift(cet), Some(Duration::minutes(10)));
+    }
+
+    #[test]
+    fn alarm_rules_parse_both_endpoint_shapes() {
+        let connections: AlarmRules = serde_json::from_str(include_str!(
+            "../../tests/fixtures/alarm_rules_connections.json"
+        ))
+        .unwrap();
+        let graph: AlarmRules =
+            serde_json::from_str(include_str!("../../tests/fixtures/alarm_rules_graph.json"))
+                .unwrap();
+
+        assert_eq!(connections.c, Some(true));
+        assert_eq!(graph.c, None);
+        assert_eq!(connections.h.f, 1.0);
+        assert_eq!(graph.h.f, 0.1);
+        assert!(graph.h.on);
+        assert_eq!((graph.h.d, graph.nd.i, graph.p), (1440, 20, 5));
+        assert_eq!((graph.l.on, graph.nd.on), (None, None));
+    }
+
+    #[test]
+    fn notification_settings_share_the_connection_types() {
+        let settings: serde_json::Value = serde_json::from_str(include_str!(
+            "../../tests/fixtures/notification_settings.json"
+        ))
+        .unwrap();
+        let rules: AlarmRules =
+            serde_json::from_value(settings["data"]["alarmRules"].clone()).unwrap();
+        let device: PatientDevice =
+            serde_json::from_value(settings["data"]["patientDevice"].clone()).unwrap();
+
+        assert_eq!((rules.l.on, rules.nd.on), (Some(true), Some(true)));
+        assert_eq!((device.l, device.h), (Some(true), Some(true)));
+        assert_eq!(device.fixed_low_threshold, Some(55.0));
+    }
+
+    #[test]
+    fn fractional_counts_are_rejected() {
+        let json = include_str!("../../tests/fixtures/alarm_rules_graph.json")
+            .replace("\"p\": 5.0", "\"p\": 5.5");
+        assert!(serde_json::from_str::<AlarmRules>(&json).is_err());
+    }
+
+    #[test]
+    fn nested_settings_tolerate_added_and_dropped_fields() {
+        let mut connection: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/connection.json")).unwrap();
+        connection["sensor"]["newSensorField"] = "added".into();
+        connection["sensor"]["pt"] = 4.0.into();
+        connection["sensor"].as_object_mut().unwrap().remove("w");
+        connection["patientDevice"]["newDeviceField"] = 1.into();
+        connection["patientDevice"]
+            .as_object_mut()
+            .unwrap()
+            .remove("fixedLowAlarmValues");
+        connection["alarmRules"]["newRule"] = serde_json::json!({ "on": true });
+        connection["alarmRules"]["h"]
+            .as_object_mut()
+            .unwrap()
+            .remove("f");
+        connection["alarmRules"]["l"]
+            .as_object_mut()
+            .unwrap()
+            .remove("th");
+        connection["alarmRules"]
+            .as_object_mut()
+            .unwrap()
+            .remove("nd");
+
+        let connection: Connection = serde_json::from_value(connection).unwrap();
+        assert_eq!((connection.sensor.pt, connection.sensor.w), (4, 0));
+        assert_eq!(connection.patient_device.fixed_low_alarm_values, None);
+        assert_eq!(connection.alarm_rules.h.f, 0.0);
+        assert_eq!(connection.alarm_rules.l.th, None);
+        assert!(connection.alarm_rules.h.th.is_some());
+        assert_eq!(connection.alarm_rules.nd, Nd::default());
+    }
+
+    #[test]
+    fn warmup_counts_down_from_activation() {
+        let connection: Connection =
+            serde_json::from_str(include_str!("../../tests/fixtures/connection.json")).unwrap();
+        let mut active = ActiveSensor {
+            sensor: connection.sensor,
+            device: connection.patient_device,
+        };
+        active.sensor.a = 1_700_000_000;
+        active.sensor.w = 60;
+        let activated = active.sensor.activated_at().unwrap();
+
+        assert_eq!(
+            active.warmup_remaining_at(activated + Duration::minutes(18)),
+            Some(Duration::minutes(42))
+        );
+        assert_eq!(
+            active.warmup_remaining_at(activated + Duration::minutes(60)),
+            None
+        );
+
+        active.sensor.a = 0;
+        assert_eq!(active.warmup_remaining_at(activated), None);
+    }
+
+    #[test]
+    fn sensor_age_and_expiry_follow_activation() {
+        let connection: Connection =
+            serde_json::from_str(include_str!("../../tests/fixtures/connection.json")).unwrap();
+        let mut sensor = connection.sensor;
+        let activated = sensor.activated_at().unwrap();
+
+        assert!(sensor.is_warming_up_at(activated + Duration::minutes(59)));
+        assert!(!sensor.is_warming_up_at(activated + Duration::minutes(60)));
+        assert_eq!(sensor.age_days_at(activated + Duration::hours(47)), Some(1));
+        assert_eq!(sensor.expires_at(), Some(activated + Duration::days(14)));
+        assert_eq!(
+            sensor.expires_after(Duration::days(15)),
+            Some(activated + Duration::days(15))
+        );
+
+        sensor.a = 0;
+        assert!(!sensor.is_warming_up_at(activated));
+        assert_eq!(sensor.age_days_at(activated), None);
+        assert_eq!(sensor.expires_at(), None);
+    }
+
+    #[test]
+    fn glucose_alarms_parse_codes_and_keep_unknown_payloads() {
+        let parse = |json: &str| serde_json::from_str::<GlucoseAlarm>(json).unwrap();
+        assert_eq!(parse("0"), GlucoseAlarm::Low);
+        assert_eq!(parse("1.0"), GlucoseAlarm::High);
+        assert_eq!(
+            parse(r#"{"alarmType": 2, "x": 1}"#),
+            GlucoseAlarm::UrgentLow
+        );
+        let unknown = parse(r#"{"kind": "new"}"#);
+        assert_eq!(unknown.code(), None);
+        assert_eq!(
+            serde_json::to_string(&unknown).unwrap(),
+            r#"{"kind":"new"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&GlucoseAlarm::UrgentLow).unwrap(),
+            "2"
+        );
+
+        let json = include_str!("../../tests/fixtures/connection.json").replace(
+            "\"glucoseAlarm\": null",
+            "\"glucoseAlarm\": {\"alarmType\": 1}",
+        );
+        let connection: Connection = serde_json::from_str(&json).unwrap();
+        assert_eq!(connection.glucose_alarm, Some(GlucoseAlarm::High));
+    }
+
+    #[test]
+    fn measurement_colors_round_trip_their_codes() {
+        for code in -1..=6 {
+            let color = MeasurementColor::from(code);
+            assert_eq!(i32::from(color), code);
+            assert_eq!(serde_json::to_string(&color).unwrap(), code.to_string());
+        }
+        assert_eq!(glucose_item().color(), MeasurementColor::InRange);
+    }
+
+    #[test]
+    fn clock_drift_ignores_timezone_offset() {
+        let cet = FixedOffset::east_opt(3600).unwrap();
+        let eastern = FixedOffset::west_opt(5 * 3600).unwrap();
+        let mut item = glucose_item();
+        item.factory_timestamp = "11/14/2023 10:15:00 AM".to_string();
+        item.timestamp = "11/14/2023 11:17:30 AM".to_string();
+        assert_eq!(item.clock_drift(cet), Some(Duration::seconds(150)));
+
+        item.timestamp = "11/14/2023 5:14:00 AM".to_string();
+        assert_eq!(item.clock_drift(eastern), Some(Duration::seconds(-60)));
+
+        item.timestamp = "not a date".to_string();
+        assert_eq!(item.clock_drift(cet), None);
+    }
+
+    #[test]
+    fn large_clock_drift_is_not_aliased() {
+        let cet = FixedOffset::east_opt(3600).unwrap();
+        let mut item = glucose_item();
+        item.factory_timestamp = "11/14/2023 10:15:00 AM".to_string();
+
+        // Beyond half of a quarter-hour offset step, which rounding to the offset would fold
+        item.timestamp = "11/14/2023 11:25:00 AM".to_string();
+        assert_eq!(item.clock_drift(cet), Some(Duration::minutes(10)));
+        item.timestamp = "11/14/2023 11:23:00 AM".to_string();
+        assert_eq!(item.clock_drift(cet), Some(Duration::minutes(8)));
+        item.timestamp = "11/14/2023 11:05:00 AM".to_string();
+        assert_eq!(item.clock_drift(cet), Some(Duration::minutes(-10)));
+        item.timestamp = "11/14/2023 12:45:00 PM".to_string();
+        assert_eq!(item.clock_drift(cet), Some(Duration::minutes(90)));
+    }
+}
@@ -10,6 +10,10 @@ pub struct AE {
     /// LSL API endpoint
     pub lsl_api: String,
     /// Socket Hub endpoint
+    ///
+    /// Real-time push endpoint used by the mobile apps. Its message protocol is not documented,
+    /// so this client does not connect to it; poll with
+    /// [`LibreLinkUpClient::sse_stream`](crate::LibreLinkUpClient::sse_stream) instead.
     pub socket_hub: String,
 }
 
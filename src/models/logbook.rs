@@ -1,9 +1,12 @@
 //! Logbook types for GET /llu/connections/{patientId}/logbook.
 
-use crate::models::common::AuthTicket;
+use crate::models::common::{AuthTicket, MeasurementColor, impl_parsed_timestamps};
 use serde::{Deserialize, Serialize};
 
 /// Single logbook entry (glucose event or alarm).
+///
+/// Entries are glucose scans and alarms only; treatment notes (food, insulin) are not
+/// available to followers.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LogbookEntry {
     #[serde(rename = "FactoryTimestamp")]
@@ -32,6 +35,15 @@ pub struct LogbookEntry {
     pub alarm_type: i32,
 }
 
+impl LogbookEntry {
+    /// Color the app shows for the entry's reading
+    pub fn color(&self) -> MeasurementColor {
+        MeasurementColor::from(self.measurement_color)
+    }
+}
+
+impl_parsed_timestamps!(LogbookEntry, "entry");
+
 /// Response from GET /llu/connections/{patientId}/logbook (authenticated).
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LogbookResponse {
@@ -129,6 +129,13 @@ pub struct UserResponse {
     pub data: Data,
 }
 
+impl UserResponse {
+    /// Whether this is a LibreLinkUp (caregiver) account; see [`User::is_caregiver_account`]
+    pub fn is_caregiver_account(&self) -> bool {
+        self.data.user.is_caregiver_account()
+    }
+}
+
 /// Data payload for GET /account (user only).
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AccountData {
@@ -204,6 +211,35 @@ pub struct User {
     pub consents: Consents,
 }
 
+impl User {
+    /// Whether this is a LibreLinkUp (caregiver) account rather than a LibreLink (patient) one
+    ///
+    /// True if the user has accepted the LibreLinkUp policy or terms of use, which only
+    /// LibreLinkUp accounts do. `accountType` cannot tell the two apart: LibreLinkUp follower
+    /// accounts report `"pat"` as well.
+    ///
+    /// The check is advisory: the consent fields are not guaranteed to be sent, so the client
+    /// never refuses a login because of it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use libre_link_up_api_client::LibreLinkUpClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = LibreLinkUpClient::simple("email@example.com".to_string(), "password".to_string(), None)?;
+    /// let user = client.get_user().await?;
+    /// if !user.is_caregiver_account() {
+    ///     eprintln!("This looks like a LibreLink account; use your LibreLinkUp login instead");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_caregiver_account(&self) -> bool {
+        self.consents.llu.policy_accept > 0 || self.consents.llu.tou_accept > 0
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 #[serde(default)]
 pub struct TwoFactor {
@@ -265,3 +301,28 @@ pub struct SystemMessages {
     #[serde(rename = "streamingTourMandatory")]
     pub streaming_tour_mandatory: Option<i64>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caregiver_detection() {
+        let patient = User {
+            account_type: "pat".to_string(),
+            ..Default::default()
+        };
+        assert!(!patient.is_caregiver_account());
+
+        let mut follower = patient.clone();
+        follower.consents.llu.tou_accept = 1_700_000_000;
+        assert!(follower.is_caregiver_account());
+
+        // Without LibreLinkUp consent the account type makes no difference
+        let other = User {
+            account_type: "other".to_string(),
+            ..Default::default()
+        };
+        assert!(!other.is_caregiver_account());
+    }
+}
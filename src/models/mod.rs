@@ -14,10 +14,14 @@ pub mod login;
 pub mod notifications;
 pub mod region;
 
-pub use client::{LibreCgmData, ReadRawResponse, ReadResponse, TrendType};
+pub use client::{
+    GlucoseClassification, GlucoseUnit, LibreCgmData, ReadRawResponse, ReadResponse, Reliability,
+    TimeSeries, TrendStrategy, TrendType,
+};
 pub use common::{
-    ActiveSensor, AlarmRules, AuthTicket, Connection, F, FixedLowAlarmValues, GlucoseItem,
-    GlucoseMeasurement, H, L, Nd, PatientDevice, Sensor, Std,
+    ActiveSensor, AlarmRules, AuthTicket, Connection, F, FixedLowAlarmValues, GlucoseAlarm,
+    GlucoseItem, GlucoseMeasurement, H, L, MeasurementColor, Nd, PatientDevice, PatientKey,
+    SENSOR_LIFETIME_DAYS, Sensor, Std,
 };
 pub use connection::{ConnectionData, ConnectionResponse};
 pub use connections::ConnectionsResponse;
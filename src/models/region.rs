@@ -1,9 +1,24 @@
 //! API region and base URL mapping.
 
+use crate::errors::{LibreLinkUpError, Result};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
 use std::fmt;
 use std::str::FromStr;
 
 /// API region; determines base URL and is used for country config and login redirects.
+///
+/// Serializes as its [`as_str`](Self::as_str) key and deserializes with
+/// [`parse_strict`](Self::parse_strict), so config files can carry `region = "eu"`.
+///
+/// # Examples
+/// ```
+/// use libre_link_up_api_client::Region;
+///
+/// assert_eq!(serde_json::to_string(&Region::EU2).unwrap(), r#""eu2""#);
+/// assert_eq!(serde_json::from_str::<Region>(r#""EU""#).unwrap(), Region::EU);
+/// assert!(serde_json::from_str::<Region>(r#""uss""#).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum Region {
     /// Global endpoint (auto-redirects to appropriate region)
@@ -31,13 +46,78 @@ pub enum Region {
     US,
     /// Latin America
     LA,
-    /// Russia
+    /// Russia (separate `libreview.ru` deployment; responses not yet verified against this client)
     RU,
-    /// China
+    /// China (separate `myfreestyle.cn` deployment; responses not yet verified against this client)
     CN,
 }
 
+/// Every region, in declaration order
+const ALL_REGIONS: [Region; 14] = [
+    Region::Global,
+    Region::AE,
+    Region::AP,
+    Region::AU,
+    Region::CA,
+    Region::DE,
+    Region::EU,
+    Region::EU2,
+    Region::FR,
+    Region::JP,
+    Region::US,
+    Region::LA,
+    Region::RU,
+    Region::CN,
+];
+
 impl Region {
+    /// Every region, in declaration order
+    ///
+    /// # Examples
+    /// ```
+    /// use libre_link_up_api_client::Region;
+    ///
+    /// assert_eq!(Region::all().first(), Some(&Region::Global));
+    /// assert!(Region::all().contains(&Region::EU2));
+    /// ```
+    pub const fn all() -> &'static [Region] {
+        &ALL_REGIONS
+    }
+
+    /// Parse a region key (case-insensitive), rejecting unrecognized values
+    ///
+    /// Unlike [`FromStr`], which falls back to [`Region::Global`], a typo such as `"uss"` is
+    /// reported instead of silently using the global endpoint. Accepts the keys returned by
+    /// [`as_str`](Self::as_str), including `"global"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LibreLinkUpError::RegionNotFound`] with the input and the available keys.
+    ///
+    /// # Examples
+    /// ```
+    /// use libre_link_up_api_client::{LibreLinkUpError, Region};
+    ///
+    /// assert_eq!(Region::parse_strict("EU2").unwrap(), Region::EU2);
+    /// assert!(matches!(
+    ///     Region::parse_strict("uss"),
+    ///     Err(LibreLinkUpError::RegionNotFound(input, _)) if input == "uss"
+    /// ));
+    /// ```
+    pub fn parse_strict(s: &str) -> Result<Region> {
+        ALL_REGIONS
+            .into_iter()
+            .find(|region| region.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let available = ALL_REGIONS
+                    .iter()
+                    .map(Region::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                LibreLinkUpError::RegionNotFound(s.to_string(), available)
+            })
+    }
+
     /// Get the base API URL for this region
     ///
     /// Returns a static string reference (no allocation)
@@ -68,6 +148,25 @@ impl Region {
         }
     }
 
+    /// Find the region whose [`base_url`](Self::base_url) matches `url`
+    ///
+    /// Matching ignores ASCII case and a trailing slash. Returns `None` for unknown hosts.
+    ///
+    /// # Examples
+    /// ```
+    /// use libre_link_up_api_client::Region;
+    ///
+    /// assert_eq!(Region::from_base_url("https://api-eu.libreview.io/"), Some(Region::EU));
+    /// assert_eq!(Region::from_base_url("https://api.libreview.ru"), Some(Region::RU));
+    /// assert_eq!(Region::from_base_url("https://example.com"), None);
+    /// ```
+    pub fn from_base_url(url: &str) -> Option<Region> {
+        let url = url.trim_end_matches('/');
+        ALL_REGIONS
+            .into_iter()
+            .find(|region| region.base_url().eq_ignore_ascii_case(url))
+    }
+
     /// Convert Region enum to string key (lowercase)
     ///
     /// # Examples
@@ -95,11 +194,39 @@ impl Region {
             Region::CN => "cn",
         }
     }
+
+    /// ISO 3166-1 alpha-2 code (lowercase) of the country this region serves
+    ///
+    /// `None` for the global endpoint and for regions serving several countries (`AP`, `EU`,
+    /// `EU2`, `LA`), whose keys are not country codes.
+    ///
+    /// # Examples
+    /// ```
+    /// use libre_link_up_api_client::Region;
+    ///
+    /// assert_eq!(Region::DE.country_code(), Some("de"));
+    /// assert_eq!(Region::EU.country_code(), None);
+    /// ```
+    pub const fn country_code(&self) -> Option<&'static str> {
+        match self {
+            Region::AE => Some("ae"),
+            Region::AU => Some("au"),
+            Region::CA => Some("ca"),
+            Region::DE => Some("de"),
+            Region::FR => Some("fr"),
+            Region::JP => Some("jp"),
+            Region::US => Some("us"),
+            Region::RU => Some("ru"),
+            Region::CN => Some("cn"),
+            Region::Global | Region::AP | Region::EU | Region::EU2 | Region::LA => None,
+        }
+    }
 }
 
 /// Parse a string into a Region (case-insensitive)
 ///
-/// Returns `Region::Global` for unrecognized region strings
+/// Returns `Region::Global` for unrecognized region strings; use [`Region::parse_strict`] to
+/// reject them instead
 ///
 /// # Examples
 /// ```
@@ -115,7 +242,7 @@ impl Region {
 impl FromStr for Region {
     type Err = std::convert::Infallible;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         let region = match s.to_lowercase().as_str() {
             "us" => Region::US,
             "eu" => Region::EU,
@@ -147,3 +274,53 @@ impl AsRef<str> for Region {
         self.as_str()
     }
 }
+
+impl Serialize for Region {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Region {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let key = Cow::<str>::deserialize(deserializer)?;
+        Region::parse_strict(&key).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_url_round_trips_for_every_region() {
+        for region in ALL_REGIONS {
+            assert_eq!(Region::from_base_url(region.base_url()), Some(region));
+        }
+    }
+
+    #[test]
+    fn serde_round_trips_every_key() {
+        for &region in Region::all() {
+            let json = serde_json::to_string(&region).unwrap();
+            assert_eq!(json, format!("\"{}\"", region.as_str()));
+            assert_eq!(serde_json::from_str::<Region>(&json).unwrap(), region);
+        }
+    }
+
+    #[test]
+    fn strict_parsing_agrees_with_from_str_for_known_keys() {
+        for &region in Region::all() {
+            let key = region.as_str().to_uppercase();
+            assert_eq!(Region::parse_strict(&key).unwrap(), region);
+            assert_eq!(Region::from_str(&key).unwrap(), region);
+        }
+        match Region::parse_strict("") {
+            Err(LibreLinkUpError::RegionNotFound(input, available)) => {
+                assert_eq!(input, "");
+                assert!(available.starts_with("global, ae, ap"));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,232 @@
+//! Reconnection policy shared by long-running polling features.
+//!
+//! Main entry: [`ReconnectPolicy`], set through
+//! [`ClientConfig::reconnect`](crate::ClientConfig::reconnect). Polling tasks such as
+//! [`read_averaged`](crate::LibreLinkUpClient::read_averaged) consult it when a poll fails and
+//! report each decision as a [`ReconnectEvent`].
+
+use crate::errors::LibreLinkUpError;
+use std::{sync::Arc, time::Duration};
+
+/// Callback invoked for every [`ReconnectEvent`]
+pub type ReconnectHook = Arc<dyn Fn(&ReconnectEvent) + Send + Sync>;
+
+/// What a polling feature does when a poll fails (network error, expired session, ...)
+///
+/// The [`Default`] retries indefinitely with exponential backoff from 1 second up to 5 minutes.
+/// Each poll already re-authenticates once on failure, so a retry here also covers token expiry.
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::{ClientConfig, ReconnectPolicy};
+/// use std::{sync::Arc, time::Duration};
+///
+/// let config = ClientConfig {
+///     username: "email@example.com".to_string(),
+///     password: "password".to_string(),
+///     reconnect: ReconnectPolicy {
+///         max_attempts: Some(10),
+///         max_delay: Duration::from_secs(60),
+///         on_event: Some(Arc::new(|event| eprintln!("{:?}", event))),
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone)]
+pub struct ReconnectPolicy {
+    /// Whether failed polls are retried; if `false` the poller stops at the first failure
+    pub enabled: bool,
+    /// Delay before the first retry; doubled after each consecutive failure
+    pub initial_delay: Duration,
+    /// Upper bound for the retry delay
+    pub max_delay: Duration,
+    /// Consecutive failures after which the poller gives up (`None` retries forever)
+    pub max_attempts: Option<u32>,
+    /// Called for every reconnection event, e.g. for logging
+    pub on_event: Option<ReconnectHook>,
+}
+
+impl ReconnectPolicy {
+    /// Policy that stops polling at the first failure
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libre_link_up_api_client::ReconnectPolicy;
+    ///
+    /// assert!(!ReconnectPolicy::never().enabled);
+    /// ```
+    pub fn never() -> Self {
+        Self {
+            enabled: false,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(300),
+            max_attempts: None,
+            on_event: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for ReconnectPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReconnectPolicy")
+            .field("enabled", &self.enabled)
+            .field("initial_delay", &self.initial_delay)
+            .field("max_delay", &self.max_delay)
+            .field("max_attempts", &self.max_attempts)
+            .field("on_event", &self.on_event.as_ref().map(|_| "<closure>"))
+            .finish()
+    }
+}
+
+/// Reconnection decision reported to [`ReconnectPolicy::on_event`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReconnectEvent {
+    /// A poll failed and will be retried after `delay`
+    Retrying {
+        /// Consecutive failures so far (starting at 1)
+        attempt: u32,
+        /// Time until the next attempt
+        delay: Duration,
+        /// The error that caused the failure
+        error: String,
+    },
+    /// A poll succeeded after `attempts` consecutive failures
+    Recovered {
+        /// Number of failures before the successful poll
+        attempts: u32,
+    },
+    /// The poller stopped after `attempts` consecutive failures
+    GaveUp {
+        /// Number of consecutive failures
+        attempts: u32,
+        /// The last error
+        error: String,
+    },
+}
+
+/// Per-poller state that applies a [`ReconnectPolicy`]
+pub(crate) struct Reconnector {
+    policy: ReconnectPolicy,
+    failures: u32,
+}
+
+impl Reconnector {
+    pub(crate) fn new(policy: ReconnectPolicy) -> Self {
+        Self {
+            policy,
+            failures: 0,
+        }
+    }
+
+    /// Record a successful poll
+    pub(crate) fn on_success(&mut self) {
+        if self.failures > 0 {
+            self.emit(ReconnectEvent::Recovered {
+                attempts: self.failures,
+            });
+            self.failures = 0;
+        }
+    }
+
+    /// Record a failed poll; returns the delay before retrying, or `None` to stop polling
+    pub(crate) fn on_error(&mut self, error: &LibreLinkUpError) -> Option<Duration> {
+        self.failures = self.failures.saturating_add(1);
+        let exhausted = self
+            .policy
+            .max_attempts
+            .is_some_and(|max| self.failures >= max);
+        if !self.policy.enabled || exhausted {
+            self.emit(ReconnectEvent::GaveUp {
+                attempts: self.failures,
+                error: error.to_string(),
+            });
+            return None;
+        }
+
+        let factor = 2u32.saturating_pow(self.failures - 1);
+        let delay = self
+            .policy
+            .initial_delay
+            .saturating_mul(factor)
+            .min(self.policy.max_delay);
+        self.emit(ReconnectEvent::Retrying {
+            attempt: self.failures,
+            delay,
+            error: error.to_string(),
+        });
+        Some(delay)
+    }
+
+    fn emit(&self, event: ReconnectEvent) {
+        if let Some(hook) = &self.policy.on_event {
+            hook(&event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn backoff_doubles_up_to_max_and_gives_up() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let mut reconnector = Reconnector::new(ReconnectPolicy {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(5),
+            max_attempts: Some(5),
+            on_event: Some(Arc::new(move |event| {
+                sink.lock().unwrap().push(event.clone())
+            })),
+            ..Default::default()
+        });
+
+        let delays: Vec<_> = (0..5)
+            .map(|_| reconnector.on_error(&LibreLinkUpError::NoConnections))
+            .collect();
+        assert_eq!(
+            delays,
+            [1, 2, 4, 5]
+                .map(|s| Some(Duration::from_secs(s)))
+                .into_iter()
+                .chain([None])
+                .collect::<Vec<_>>()
+        );
+        assert!(matches!(
+            events.lock().unwrap().last(),
+            Some(ReconnectEvent::GaveUp { attempts: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn success_resets_backoff() {
+        let mut reconnector = Reconnector::new(ReconnectPolicy::default());
+        reconnector.on_error(&LibreLinkUpError::NoConnections);
+        reconnector.on_error(&LibreLinkUpError::NoConnections);
+        reconnector.on_success();
+        assert_eq!(
+            reconnector.on_error(&LibreLinkUpError::NoConnections),
+            Some(Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn never_stops_at_first_failure() {
+        let mut reconnector = Reconnector::new(ReconnectPolicy::never());
+        assert_eq!(reconnector.on_error(&LibreLinkUpError::NoConnections), None);
+    }
+}
@@ -0,0 +1,549 @@
+//! Standardized CGM report metrics (international consensus on time in range).
+//!
+//! Main entry: [`agp_metrics`], which bundles TIR, TBR/TAR tiers, mean, GMI, CV, and the
+//! Glycemia Risk Index (GRI) for a set of [`LibreCgmData`] readings.
+//!
+//! Percentages are computed per reading, which assumes readings are evenly spaced (as with the
+//! 15-minute graph history). All glucose values and thresholds are in mg/dL.
+
+use crate::models::{
+    client::LibreCgmData, common::Connection, notifications::NotificationSettingsData,
+};
+#[cfg(feature = "timezone")]
+use chrono::NaiveDate;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "timezone")]
+use std::collections::BTreeMap;
+
+/// Glucose thresholds (mg/dL) used to bucket readings into consensus ranges.
+///
+/// The [`Default`] is the consensus target range for most adults with type 1/type 2 diabetes:
+/// very low < 54, low < 70, high > 180, very high > 250.
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::report::TargetRange;
+///
+/// let targets = TargetRange::default();
+/// assert_eq!((targets.low, targets.high), (70.0, 180.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TargetRange {
+    /// Level 2 hypoglycemia threshold; readings below this are "very low"
+    pub very_low: f64,
+    /// Lower bound of the target range (inclusive)
+    pub low: f64,
+    /// Upper bound of the target range (inclusive)
+    pub high: f64,
+    /// Level 2 hyperglycemia threshold; readings above this are "very high"
+    pub very_high: f64,
+}
+
+impl Default for TargetRange {
+    fn default() -> Self {
+        Self {
+            very_low: 54.0,
+            low: 70.0,
+            high: 180.0,
+            very_high: 250.0,
+        }
+    }
+}
+
+impl TargetRange {
+    /// The target range configured on a patient's connection, or `None` if it is not set
+    ///
+    /// Uses `target_low`/`target_high` if both are positive and `low < high`; the very low and
+    /// very high thresholds are the consensus values, widened like in
+    /// [`reconcile`](Self::reconcile).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use libre_link_up_api_client::LibreLinkUpClient;
+    /// use libre_link_up_api_client::report::TargetRange;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = LibreLinkUpClient::simple("email@example.com".to_string(), "password".to_string(), None)?;
+    /// let raw = client.read_raw().await?;
+    /// let targets = TargetRange::from_connection(&raw.connection).unwrap_or_default();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_connection(connection: &Connection) -> Option<Self> {
+        Self::from_bounds(connection.target_low, connection.target_high)
+    }
+
+    /// Consensus range with `low`/`high` replaced, if they form a valid pair
+    fn from_bounds(low: f64, high: f64) -> Option<Self> {
+        if !(low > 0.0 && low < high) {
+            return None;
+        }
+        let defaults = Self::default();
+        Some(Self {
+            very_low: defaults.very_low.min(low),
+            low,
+            high,
+            very_high: defaults.very_high.max(high),
+        })
+    }
+
+    /// Reconcile a patient's target range from their connection and notification settings
+    ///
+    /// Precedence for `low`/`high`:
+    /// 1. the connection's `target_low`/`target_high` (the range the app shades as "in target"),
+    /// 2. otherwise the low/high alarm thresholds from the notification settings,
+    /// 3. otherwise the consensus defaults.
+    ///
+    /// A pair is only used if both values are positive and `low < high`. The API has no "very
+    /// low"/"very high" settings, so those keep the consensus values, widened if needed so they
+    /// never fall inside the target range.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use libre_link_up_api_client::LibreLinkUpClient;
+    /// use libre_link_up_api_client::report::TargetRange;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = LibreLinkUpClient::simple("email@example.com".to_string(), "password".to_string(), None)?;
+    /// let raw = client.read_raw().await?;
+    /// let settings = client.get_notification_settings(&raw.connection.patient_id).await?;
+    /// let targets = TargetRange::reconcile(&raw.connection, &settings.data);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reconcile(connection: &Connection, settings: &NotificationSettingsData) -> Self {
+        let rules = &settings.alarm_rules;
+        Self::from_connection(connection)
+            .or_else(|| Self::from_bounds(rules.l.th?, rules.h.th?))
+            .unwrap_or_default()
+    }
+}
+
+/// Consensus CGM metrics for a set of readings. Percentages are in the range 0–100.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgpMetrics {
+    /// Number of readings the metrics were computed from
+    pub readings: usize,
+    /// Mean glucose in mg/dL (`None` if there are no readings)
+    pub mean: Option<f64>,
+    /// Glucose Management Indicator in % (`None` if there are no readings)
+    pub gmi: Option<f64>,
+    /// Coefficient of variation in % (`None` if there are no readings)
+    pub cv: Option<f64>,
+    /// Time in range (`low..=high`)
+    pub time_in_range: f64,
+    /// Time below range, level 1 (`>= very_low` and `< low`)
+    pub time_below_range: f64,
+    /// Time below range, level 2 (`< very_low`)
+    pub time_very_low: f64,
+    /// Time above range, level 1 (`> high` and `<= very_high`)
+    pub time_above_range: f64,
+    /// Time above range, level 2 (`> very_high`)
+    pub time_very_high: f64,
+    /// Glycemia Risk Index (0–100)
+    pub gri: f64,
+}
+
+/// Computes the consensus CGM report metrics for `readings` against `targets`.
+///
+/// - **GMI** = 3.31 + 0.02392 × mean (mg/dL)
+/// - **CV** = sample standard deviation / mean × 100
+/// - **GRI** = 3.0 × VLow + 2.4 × Low + 1.6 × VHigh + 0.8 × High, capped at 100
+///
+/// GRI is defined on the consensus thresholds, so use [`TargetRange::default`] for a
+/// standard score.
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::{LibreCgmData, TrendType};
+/// use libre_link_up_api_client::report::{TargetRange, agp_metrics};
+/// use chrono::Utc;
+///
+/// #[allow(deprecated)]
+/// let readings: Vec<LibreCgmData> = [100.0, 150.0, 200.0]
+///     .into_iter()
+///     .map(|value| LibreCgmData {
+///         value,
+///         mgdl: value,
+///         mmol: value / 18.0182,
+///         is_high: false,
+///         is_low: false,
+///         trend: TrendType::Flat,
+///         date: Utc::now(),
+///     })
+///     .collect();
+///
+/// let metrics = agp_metrics(&readings, &TargetRange::default());
+/// assert_eq!(metrics.mean, Some(150.0));
+/// assert!((metrics.time_in_range - 200.0 / 3.0).abs() < 1e-9);
+/// ```
+pub fn agp_metrics(readings: &[LibreCgmData], targets: &TargetRange) -> AgpMetrics {
+    let n = readings.len();
+    if n == 0 {
+        return AgpMetrics {
+            readings: 0,
+            mean: None,
+            gmi: None,
+            cv: None,
+            time_in_range: 0.0,
+            time_below_range: 0.0,
+            time_very_low: 0.0,
+            time_above_range: 0.0,
+            time_very_high: 0.0,
+            gri: 0.0,
+        };
+    }
+
+    let (mut very_low, mut low, mut in_range, mut high, mut very_high) = (0, 0, 0, 0, 0);
+    for reading in readings {
+        let v = reading.mgdl;
+        if v < targets.very_low {
+            very_low += 1;
+        } else if v < targets.low {
+            low += 1;
+        } else if v <= targets.high {
+            in_range += 1;
+        } else if v <= targets.very_high {
+            high += 1;
+        } else {
+            very_high += 1;
+        }
+    }
+    let pct = |count: usize| count as f64 / n as f64 * 100.0;
+
+    let mean = readings.iter().map(|r| r.mgdl).sum::<f64>() / n as f64;
+    let sd = if n > 1 {
+        (readings
+            .iter()
+            .map(|r| (r.mgdl - mean).powi(2))
+            .sum::<f64>()
+            / (n - 1) as f64)
+            .sqrt()
+    } else {
+        0.0
+    };
+
+    let (time_very_low, time_below_range) = (pct(very_low), pct(low));
+    let (time_above_range, time_very_high) = (pct(high), pct(very_high));
+    let gri = (3.0 * time_very_low
+        + 2.4 * time_below_range
+        + 1.6 * time_very_high
+        + 0.8 * time_above_range)
+        .min(100.0);
+
+    AgpMetrics {
+        readings: n,
+        mean: Some(mean),
+        gmi: Some(3.31 + 0.02392 * mean),
+        cv: Some(if mean > 0.0 { sd / mean * 100.0 } else { 0.0 }),
+        time_in_range: pct(in_range),
+        time_below_range,
+        time_very_low,
+        time_above_range,
+        time_very_high,
+        gri,
+    }
+}
+
+/// Headline glucose statistics for a dashboard, see [`ReadResponse::stats`]. Percentages are
+/// in the range 0–100.
+///
+/// A simpler view of [`AgpMetrics`] with a single target range and no level 2 tiers.
+///
+/// [`ReadResponse::stats`]: crate::ReadResponse::stats
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlucoseStats {
+    /// Number of readings the statistics were computed from
+    pub readings: usize,
+    /// Mean glucose in mg/dL (`None` if there are no readings)
+    pub average: Option<f64>,
+    /// Glucose Management Indicator in %, `3.31 + 0.02392 × average` (`None` if there are no
+    /// readings)
+    pub gmi: Option<f64>,
+    /// Time in range (`low..=high`)
+    pub time_in_range: f64,
+    /// Time below range (`< low`)
+    pub time_below_range: f64,
+    /// Time above range (`> high`)
+    pub time_above_range: f64,
+}
+
+impl GlucoseStats {
+    /// Computes the statistics for `readings` against the target range `low..=high` (mg/dL)
+    ///
+    /// With no readings the averages are `None` and every percentage is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libre_link_up_api_client::report::GlucoseStats;
+    ///
+    /// let stats = GlucoseStats::from_readings(&[], 70.0, 180.0);
+    /// assert_eq!((stats.readings, stats.average, stats.time_in_range), (0, None, 0.0));
+    /// ```
+    pub fn from_readings(readings: &[LibreCgmData], low: f64, high: f64) -> Self {
+        // With the level 2 thresholds on the range bounds, every out-of-range reading lands
+        // in the "very" tiers
+        let metrics = agp_metrics(
+            readings,
+            &TargetRange {
+                very_low: low,
+                low,
+                high,
+                very_high: high,
+            },
+        );
+        Self {
+            readings: metrics.readings,
+            average: metrics.mean,
+            gmi: metrics.gmi,
+            time_in_range: metrics.time_in_range,
+            time_below_range: metrics.time_very_low + metrics.time_below_range,
+            time_above_range: metrics.time_above_range + metrics.time_very_high,
+        }
+    }
+}
+
+/// Computes [`agp_metrics`] separately for each local calendar day in `tz`.
+///
+/// Day boundaries follow local midnight, so daily TIR matches what the user sees in the app.
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::{LibreCgmData, TrendType};
+/// use libre_link_up_api_client::report::{TargetRange, daily_agp_metrics};
+/// use chrono::{TimeZone, Utc};
+/// use chrono_tz::America::New_York;
+///
+/// #[allow(deprecated)]
+/// let reading = |hour, value| LibreCgmData {
+///     value,
+///     mgdl: value,
+///     mmol: value / 18.0182,
+///     is_high: false,
+///     is_low: false,
+///     trend: TrendType::Flat,
+///     date: Utc.with_ymd_and_hms(2024, 1, 2, hour, 0, 0).unwrap(),
+/// };
+/// // 03:00 UTC is still January 1st in New York
+/// let days = daily_agp_metrics(&[reading(3, 100.0), reading(15, 200.0)], &TargetRange::default(), New_York);
+/// assert_eq!(days.len(), 2);
+/// ```
+#[cfg(feature = "timezone")]
+pub fn daily_agp_metrics(
+    readings: &[LibreCgmData],
+    targets: &TargetRange,
+    tz: chrono_tz::Tz,
+) -> BTreeMap<NaiveDate, AgpMetrics> {
+    let mut days: BTreeMap<NaiveDate, Vec<LibreCgmData>> = BTreeMap::new();
+    for reading in readings {
+        days.entry(reading.local_date(tz).date_naive())
+            .or_default()
+            .push(reading.clone());
+    }
+    days.into_iter()
+        .map(|(day, readings)| (day, agp_metrics(&readings, targets)))
+        .collect()
+}
+
+/// Percentage (0–100) of the expected readings that were actually captured in the `window`
+/// ending now.
+///
+/// Expected readings are `window / expected_interval` (e.g. 96 for 24 hours of the 15-minute
+/// graph history); readings with the same timestamp are counted once, and the result is capped
+/// at 100. Consensus guidance treats AGP metrics as representative only when the sensor was
+/// active for at least 70% of the reporting period. Returns 0 if `window` or
+/// `expected_interval` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::{LibreCgmData, TrendType};
+/// use libre_link_up_api_client::report::active_percentage;
+/// use chrono::{Duration, Utc};
+///
+/// let now = Utc::now();
+/// // A 6-hour gap in the last 24 hours of 15-minute readings
+/// #[allow(deprecated)]
+/// let readings: Vec<LibreCgmData> = (0..72)
+///     .map(|i| LibreCgmData {
+///         value: 120.0,
+///         mgdl: 120.0,
+///         mmol: 120.0 / 18.0182,
+///         is_high: false,
+///         is_low: false,
+///         trend: TrendType::Flat,
+///         date: now - Duration::minutes(15 * i),
+///     })
+///     .collect();
+///
+/// let active = active_percentage(&readings, Duration::hours(24), Duration::minutes(15));
+/// assert_eq!(active, 75.0);
+/// ```
+pub fn active_percentage(
+    readings: &[LibreCgmData],
+    window: Duration,
+    expected_interval: Duration,
+) -> f64 {
+    if window <= Duration::zero() || expected_interval <= Duration::zero() {
+        return 0.0;
+    }
+    let expected = window.num_milliseconds() / expected_interval.num_milliseconds();
+    if expected == 0 {
+        return 0.0;
+    }
+
+    let end = Utc::now();
+    let start = end - window;
+    let mut dates: Vec<DateTime<Utc>> = readings
+        .iter()
+        .map(|r| r.date)
+        .filter(|date| *date > start && *date <= end)
+        .collect();
+    dates.sort_unstable();
+    dates.dedup();
+
+    (dates.len() as f64 / expected as f64 * 100.0).min(100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::client::TrendType;
+
+    #[allow(deprecated)]
+    fn readings(values: &[f64]) -> Vec<LibreCgmData> {
+        values
+            .iter()
+            .map(|&value| LibreCgmData {
+                value,
+                mgdl: value,
+                mmol: value / 18.0182,
+                is_high: false,
+                is_low: false,
+                trend: TrendType::Flat,
+                date: Utc::now(),
+            })
+            .collect()
+    }
+
+    fn connection_and_settings() -> (Connection, NotificationSettingsData) {
+        let connection: Connection =
+            serde_json::from_str(include_str!("../tests/fixtures/connection.json")).unwrap();
+        let settings: crate::models::NotificationSettingsResponse =
+            serde_json::from_str(include_str!("../tests/fixtures/notification_settings.json"))
+                .unwrap();
+        (connection, settings.data)
+    }
+
+    #[test]
+    fn active_percentage_counts_readings_in_window() {
+        let now = Utc::now();
+        let mut data = readings(&[100.0; 6]);
+        let offsets = [10, 25, 25, 40, 55, 200];
+        for (reading, minutes) in data.iter_mut().zip(offsets) {
+            reading.date = now - Duration::minutes(minutes);
+        }
+
+        // 4 distinct readings in the last hour out of 4 expected; the duplicate and the
+        // reading outside the window are not counted
+        let hour = Duration::hours(1);
+        assert_eq!(active_percentage(&data, hour, Duration::minutes(15)), 100.0);
+        assert_eq!(
+            active_percentage(&data, hour, Duration::minutes(5)),
+            4.0 / 12.0 * 100.0
+        );
+        assert_eq!(active_percentage(&data, hour, Duration::zero()), 0.0);
+        assert_eq!(active_percentage(&[], hour, Duration::minutes(15)), 0.0);
+    }
+
+    #[test]
+    fn glucose_stats_split_at_the_given_range() {
+        let stats = GlucoseStats::from_readings(
+            &readings(&[50.0, 69.9, 70.0, 120.0, 180.0, 180.1, 300.0, 130.0]),
+            70.0,
+            180.0,
+        );
+        assert_eq!(stats.readings, 8);
+        assert_eq!(
+            (
+                stats.time_below_range,
+                stats.time_in_range,
+                stats.time_above_range
+            ),
+            (25.0, 50.0, 25.0)
+        );
+        let average = stats.average.unwrap();
+        assert!((average - 137.5).abs() < 1e-9);
+        assert!((stats.gmi.unwrap() - (3.31 + 0.02392 * average)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn connection_targets_take_precedence() {
+        let (connection, settings) = connection_and_settings();
+        let targets = TargetRange::reconcile(&connection, &settings);
+        assert_eq!((targets.low, targets.high), (70.0, 180.0));
+    }
+
+    #[test]
+    fn alarm_thresholds_fill_in_missing_targets() {
+        let (mut connection, settings) = connection_and_settings();
+        connection.target_low = 0.0;
+        connection.target_high = 0.0;
+        assert_eq!(TargetRange::from_connection(&connection), None);
+        let targets = TargetRange::reconcile(&connection, &settings);
+        assert_eq!((targets.low, targets.high), (75.0, 240.0));
+
+        connection.target_low = 50.0;
+        connection.target_high = 300.0;
+        let targets = TargetRange::reconcile(&connection, &settings);
+        assert_eq!((targets.very_low, targets.very_high), (50.0, 300.0));
+    }
+
+    #[test]
+    fn empty_readings_have_no_mean() {
+        let metrics = agp_metrics(&[], &TargetRange::default());
+        assert_eq!(metrics.readings, 0);
+        assert_eq!(metrics.mean, None);
+        assert_eq!(metrics.gri, 0.0);
+    }
+
+    #[test]
+    fn gri_matches_published_example() {
+        // Klonoff et al. (2022): VLow 1%, Low 3%, VHigh 5%, High 20% => GRI 34.2
+        let mut values = vec![50.0; 1];
+        values.extend([60.0; 3]);
+        values.extend([300.0; 5]);
+        values.extend([200.0; 20]);
+        values.extend([120.0; 71]);
+
+        let metrics = agp_metrics(&readings(&values), &TargetRange::default());
+        assert!((metrics.time_very_low - 1.0).abs() < 1e-9);
+        assert!((metrics.time_below_range - 3.0).abs() < 1e-9);
+        assert!((metrics.time_very_high - 5.0).abs() < 1e-9);
+        assert!((metrics.time_above_range - 20.0).abs() < 1e-9);
+        assert!((metrics.time_in_range - 71.0).abs() < 1e-9);
+        assert!((metrics.gri - 34.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn gmi_matches_published_example() {
+        // Bergenstal et al. (2018): mean 150 mg/dL => GMI 6.9%
+        let metrics = agp_metrics(&readings(&[120.0, 150.0, 180.0]), &TargetRange::default());
+        assert_eq!(metrics.mean, Some(150.0));
+        assert!((metrics.gmi.unwrap() - 6.898).abs() < 1e-9);
+        assert!((metrics.cv.unwrap() - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn range_boundaries_are_inclusive() {
+        let metrics = agp_metrics(&readings(&[70.0, 180.0]), &TargetRange::default());
+        assert_eq!(metrics.time_in_range, 100.0);
+    }
+}
@@ -0,0 +1,87 @@
+//! Retry policy for transient request failures.
+//!
+//! Main entry: [`RetryConfig`], set through [`ClientConfig::retry`](crate::ClientConfig::retry).
+//! Only idempotent GET requests are retried, and only on connection errors, timeouts, and 5xx
+//! responses; when the attempts run out the last error is wrapped in
+//! [`LibreLinkUpError::RetriesExhausted`](crate::LibreLinkUpError::RetriesExhausted).
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// Exponential backoff for retrying GET requests that failed transiently
+///
+/// The delay before retry `n` is `base_delay × 2ⁿ⁻¹`, capped at `max_delay`, with up to half
+/// of it removed at random so that many clients recovering from the same outage do not retry
+/// in lockstep. The [`Default`] makes 3 attempts starting at 500 ms, capped at 10 seconds.
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::{ClientConfig, RetryConfig};
+/// use std::time::Duration;
+///
+/// let config = ClientConfig {
+///     username: "email@example.com".to_string(),
+///     password: "password".to_string(),
+///     retry: Some(RetryConfig {
+///         max_attempts: 5,
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// Total attempts per request, including the first (values below 1 are treated as 1)
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled after each further failure
+    pub base_delay: Duration,
+    /// Upper bound for the delay between attempts
+    pub max_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Delay before retrying after `attempt` (starting at 1) failed attempts, with jitter
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        // Each `RandomState` is seeded differently, which is random enough for jitter
+        let random = RandomState::new().build_hasher().finish();
+        let fraction = (random >> 11) as f64 / (1u64 << 53) as f64;
+        delay.mul_f64(1.0 - fraction / 2.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delays_grow_exponentially_within_jitter_bounds() {
+        let retry = RetryConfig {
+            max_attempts: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+        };
+        for (attempt, full) in [(1, 100), (2, 200), (3, 400), (4, 500), (9, 500)] {
+            let full = Duration::from_millis(full);
+            for _ in 0..20 {
+                let delay = retry.delay(attempt);
+                assert!(delay <= full && delay >= full / 2, "{attempt}: {delay:?}");
+            }
+        }
+    }
+}
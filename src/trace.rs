@@ -0,0 +1,58 @@
+//! Logging macros that forward to [`tracing`](https://docs.rs/tracing) when the `tracing`
+//! feature is enabled and otherwise only borrow their arguments, so variables that are only
+//! logged do not trigger unused warnings.
+//!
+//! `warning` stands in for `tracing::warn`, whose name clashes with the built-in `warn`
+//! attribute when defined as a local macro.
+
+#[cfg(feature = "tracing")]
+pub(crate) use tracing::{debug, info, warn as warning};
+
+/// Borrows each field value and format argument of a `tracing` event
+#[cfg(not(feature = "tracing"))]
+macro_rules! consume {
+    () => {};
+    ($field:ident = % $value:expr $(, $($rest:tt)*)?) => {
+        let _ = &$value;
+        $($crate::trace::consume!($($rest)*);)?
+    };
+    ($field:ident = ? $value:expr $(, $($rest:tt)*)?) => {
+        let _ = &$value;
+        $($crate::trace::consume!($($rest)*);)?
+    };
+    ($field:ident = $value:expr $(, $($rest:tt)*)?) => {
+        let _ = &$value;
+        $($crate::trace::consume!($($rest)*);)?
+    };
+    ($field:ident $(, $($rest:tt)*)?) => {
+        let _ = &$field;
+        $($crate::trace::consume!($($rest)*);)?
+    };
+    ($message:literal $(, $arg:expr)* $(,)?) => {
+        $(let _ = &$arg;)*
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! debug {
+    ($($arg:tt)*) => {{
+        $crate::trace::consume!($($arg)*);
+    }};
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! info {
+    ($($arg:tt)*) => {{
+        $crate::trace::consume!($($arg)*);
+    }};
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! warning {
+    ($($arg:tt)*) => {{
+        $crate::trace::consume!($($arg)*);
+    }};
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) use {consume, debug, info, warning};
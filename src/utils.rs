@@ -1,13 +1,67 @@
 //! Utilities for mapping API glucose data to [`LibreCgmData`].
 //!
-//! Main entry: [`map_glucose_data`].
+//! Main entry: [`map_glucose_data`]. Also includes [`normalize_api_version`] for validating
+//! the configured API version.
 
+use crate::errors::{LibreLinkUpError, Result};
 use crate::models::{
     LibreCgmData,
-    client::TrendType,
+    client::{GlucoseUnit, TrendType},
     common::{GlucoseItem, GlucoseMeasurement},
 };
-use chrono::Utc;
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
+
+/// Conversion factor between mg/dL and mmol/L (molar mass of glucose / 10).
+pub const MGDL_PER_MMOL: f64 = 18.0182;
+
+/// Converts a glucose value from mg/dL to mmol/L.
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::utils::mgdl_to_mmol;
+///
+/// assert!((mgdl_to_mmol(180.0) - 9.99).abs() < 0.01);
+/// ```
+pub fn mgdl_to_mmol(mgdl: f64) -> f64 {
+    mgdl / MGDL_PER_MMOL
+}
+
+/// `GlucoseUnits` code for mmol/L
+const GLUCOSE_UNITS_MMOL: i32 = 0;
+/// `GlucoseUnits` code for mg/dL
+const GLUCOSE_UNITS_MGDL: i32 = 1;
+
+/// Unit of a `GlucoseUnits` code, `None` for an unknown code
+pub(crate) fn glucose_unit(glucose_units: i32) -> Option<GlucoseUnit> {
+    match glucose_units {
+        GLUCOSE_UNITS_MGDL => Some(GlucoseUnit::MgDl),
+        GLUCOSE_UNITS_MMOL => Some(GlucoseUnit::MmolL),
+        _ => None,
+    }
+}
+
+/// Converts a glucose item's `Value` to mg/dL according to the item's own `GlucoseUnits`
+/// (0 = mmol/L, 1 = mg/dL).
+///
+/// Returns `None` for an unknown unit code.
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::utils::value_to_mgdl;
+///
+/// assert_eq!(value_to_mgdl(112.0, 1), Some(112.0));
+/// assert!((value_to_mgdl(6.2, 0).unwrap() - 111.7).abs() < 0.1);
+/// assert_eq!(value_to_mgdl(112.0, 7), None);
+/// ```
+pub fn value_to_mgdl(value: f64, glucose_units: i32) -> Option<f64> {
+    match glucose_units {
+        GLUCOSE_UNITS_MGDL => Some(value),
+        GLUCOSE_UNITS_MMOL => Some(value * MGDL_PER_MMOL),
+        _ => None,
+    }
+}
 
 /// Maps API trend arrow index (0–6) to [`TrendType`]. Used when converting raw readings.
 pub const TREND_MAP: [TrendType; 7] = [
@@ -27,13 +81,58 @@ pub fn get_trend(trend_arrow: Option<i32>) -> TrendType {
         .unwrap_or(TrendType::Flat)
 }
 
+/// Timestamp format used by the API (`"11/14/2023 10:15:00 AM"`).
+pub const API_TIMESTAMP_FORMAT: &str = "%m/%d/%Y %I:%M:%S %p";
+
+/// Parses an API timestamp as UTC, returning `None` if it matches no known format.
+///
+/// Accepts [`API_TIMESTAMP_FORMAT`] (month/day/hour may omit leading zeros), RFC 3339, and
+/// ISO 8601 without an offset (`"2023-11-14T10:15:00"`). Timestamps without an offset are
+/// interpreted as UTC.
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::utils::parse_timestamp;
+///
+/// let ts = parse_timestamp("1/2/2024 3:04:05 PM").unwrap();
+/// assert_eq!(ts.to_rfc3339(), "2024-01-02T15:04:05+00:00");
+/// assert!(parse_timestamp("yesterday").is_none());
+/// ```
+pub fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    parse_with_offset(s).map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Parses an API timestamp as wall-clock time, returning `None` if it matches no known format.
+///
+/// Accepts the same formats as [`parse_timestamp`], but an RFC 3339 offset is dropped rather
+/// than applied, so `"2023-11-14T11:15:00+01:00"` gives 11:15.
+pub(crate) fn parse_local_timestamp(s: &str) -> Option<NaiveDateTime> {
+    parse_with_offset(s).map(|dt| dt.naive_local())
+}
+
+/// Timestamps without an offset are given a UTC one
+fn parse_with_offset(s: &str) -> Option<DateTime<FixedOffset>> {
+    let s = s.trim();
+    NaiveDateTime::parse_from_str(s, API_TIMESTAMP_FORMAT)
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f"))
+        .map(|naive| naive.and_utc().fixed_offset())
+        .ok()
+        .or_else(|| DateTime::parse_from_rfc3339(s).ok())
+}
+
 /// Implemented by API types that can be converted to [`LibreCgmData`] via [`map_glucose_data`].
 pub trait GlucoseData {
     fn factory_timestamp(&self) -> &str;
     fn value(&self) -> f64;
+    fn value_in_mg_per_dl(&self) -> f64;
     fn is_high(&self) -> bool;
     fn is_low(&self) -> bool;
     fn trend_arrow(&self) -> Option<i32>;
+    /// Unit code of [`value`](Self::value) (0 = mmol/L, 1 = mg/dL), if known
+    fn glucose_units(&self) -> Option<i32> {
+        None
+    }
 }
 
 impl GlucoseData for GlucoseItem {
@@ -43,6 +142,9 @@ impl GlucoseData for GlucoseItem {
     fn value(&self) -> f64 {
         self.value
     }
+    fn value_in_mg_per_dl(&self) -> f64 {
+        self.value_in_mg_per_dl
+    }
     fn is_high(&self) -> bool {
         self.is_high
     }
@@ -52,6 +154,9 @@ impl GlucoseData for GlucoseItem {
     fn trend_arrow(&self) -> Option<i32> {
         self.trend_arrow
     }
+    fn glucose_units(&self) -> Option<i32> {
+        Some(self.glucose_units)
+    }
 }
 
 impl GlucoseData for GlucoseMeasurement {
@@ -61,6 +166,9 @@ impl GlucoseData for GlucoseMeasurement {
     fn value(&self) -> f64 {
         self.value
     }
+    fn value_in_mg_per_dl(&self) -> f64 {
+        self.value_in_mg_per_dl
+    }
     fn is_high(&self) -> bool {
         self.is_high
     }
@@ -70,19 +178,216 @@ impl GlucoseData for GlucoseMeasurement {
     fn trend_arrow(&self) -> Option<i32> {
         Some(self.trend_arrow)
     }
+    fn glucose_units(&self) -> Option<i32> {
+        Some(self.glucose_units)
+    }
 }
 
-/// Converts a [`GlucoseData`] item (e.g. [`GlucoseItem`], [`GlucoseMeasurement`]) into [`LibreCgmData`]. Uses [`get_trend`] for the trend; parses timestamp or falls back to now.
+/// Converts a [`GlucoseData`] item (e.g. [`GlucoseItem`], [`GlucoseMeasurement`]) into [`LibreCgmData`]. Uses [`get_trend`] for the trend and [`parse_timestamp`] for the date.
+///
+/// If `FactoryTimestamp` cannot be parsed the date falls back to now, which misplaces the
+/// reading; use [`try_map_glucose_data`] to detect that instead.
+///
+/// `mgdl` comes from `ValueInMgPerDl` and `mmol` is computed from it, so both are populated
+/// regardless of the account's configured unit. If `ValueInMgPerDl` is missing, `Value` is
+/// converted using the item's own `GlucoseUnits` rather than the connection's `uom`, since the
+/// two can disagree for a while after the user switches units.
 pub fn map_glucose_data<T: GlucoseData>(item: &T) -> LibreCgmData {
-    let date = format!("{} UTC", item.factory_timestamp())
-        .parse()
-        .unwrap_or_else(|_| Utc::now());
+    let date = parse_timestamp(item.factory_timestamp()).unwrap_or_else(Utc::now);
+    map_glucose_data_at(item, date)
+}
+
+/// Like [`map_glucose_data`], but fails instead of substituting the current time when
+/// `FactoryTimestamp` cannot be parsed.
+///
+/// # Errors
+///
+/// Returns [`LibreLinkUpError::InvalidResponse`] if the timestamp matches none of the formats
+/// accepted by [`parse_timestamp`].
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::models::Connection;
+/// use libre_link_up_api_client::utils::try_map_glucose_data;
+///
+/// # let connection: Connection = serde_json::from_str(include_str!("../tests/fixtures/connection.json")).unwrap();
+/// let mut item = connection.glucose_item;
+/// assert_eq!(
+///     try_map_glucose_data(&item)?.date.to_rfc3339(),
+///     "2023-11-14T10:15:00+00:00"
+/// );
+///
+/// item.factory_timestamp = "garbage".to_string();
+/// assert!(try_map_glucose_data(&item).is_err());
+/// # Ok::<(), libre_link_up_api_client::LibreLinkUpError>(())
+/// ```
+pub fn try_map_glucose_data<T: GlucoseData>(item: &T) -> Result<LibreCgmData> {
+    let date = parse_timestamp(item.factory_timestamp()).ok_or_else(|| {
+        LibreLinkUpError::InvalidResponse(format!(
+            "unparseable FactoryTimestamp '{}'",
+            item.factory_timestamp()
+        ))
+    })?;
+    Ok(map_glucose_data_at(item, date))
+}
+
+#[allow(deprecated)]
+fn map_glucose_data_at<T: GlucoseData>(item: &T, date: DateTime<Utc>) -> LibreCgmData {
+    let mgdl = match item.value_in_mg_per_dl() {
+        mgdl if mgdl > 0.0 => mgdl,
+        missing => item
+            .glucose_units()
+            .and_then(|units| value_to_mgdl(item.value(), units))
+            .unwrap_or(missing),
+    };
 
     LibreCgmData {
         value: item.value(),
+        mgdl,
+        mmol: mgdl_to_mmol(mgdl),
         is_high: item.is_high(),
         is_low: item.is_low(),
         trend: get_trend(item.trend_arrow()),
         date,
     }
 }
+
+/// Like [`map_glucose_data`], but with `value` expressed in `unit` and rounded to that unit's
+/// display precision (see [`GlucoseUnit::round`]).
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::GlucoseUnit;
+/// use libre_link_up_api_client::models::Connection;
+/// use libre_link_up_api_client::utils::map_glucose_data_in;
+///
+/// # let connection: Connection = serde_json::from_str(include_str!("../tests/fixtures/connection.json")).unwrap();
+/// // 112 mg/dL
+/// let reading = map_glucose_data_in(&connection.glucose_item, GlucoseUnit::MmolL);
+/// #[allow(deprecated)]
+/// let value = reading.value;
+/// assert_eq!(value, 6.2);
+/// ```
+#[allow(deprecated)]
+pub fn map_glucose_data_in<T: GlucoseData>(item: &T, unit: GlucoseUnit) -> LibreCgmData {
+    let mut data = map_glucose_data(item);
+    data.value = unit.round(data.value_in(unit));
+    data
+}
+
+/// Validates an API version string and normalizes it to `major.minor.patch`.
+///
+/// Missing minor/patch components are padded with zeros (`"4.16"` → `"4.16.0"`). Surrounding
+/// whitespace is trimmed.
+///
+/// # Errors
+///
+/// Returns [`LibreLinkUpError::InvalidConfig`] if the version is empty, has more than three
+/// components, or contains a non-numeric component.
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::utils::normalize_api_version;
+///
+/// assert_eq!(normalize_api_version("4.16").unwrap(), "4.16.0");
+/// assert_eq!(normalize_api_version(" 4.16.0 ").unwrap(), "4.16.0");
+/// assert!(normalize_api_version("4.16.0-beta").is_err());
+/// assert!(normalize_api_version("v4").is_err());
+/// ```
+pub fn normalize_api_version(version: &str) -> Result<String> {
+    let [major, minor, patch] = api_version_parts(version)?;
+    Ok(format!("{}.{}.{}", major, minor, patch))
+}
+
+/// Parses an API version into its numeric `[major, minor, patch]` components, padding missing
+/// ones with zeros. Arrays compare lexicographically, so the result orders versions correctly.
+pub(crate) fn api_version_parts(version: &str) -> Result<[u64; 3]> {
+    let invalid = || {
+        LibreLinkUpError::InvalidConfig(format!(
+            "api_version '{}' is not a valid major.minor.patch version",
+            version
+        ))
+    };
+
+    let parts: Vec<&str> = version.trim().split('.').collect();
+    if parts.len() > 3
+        || parts
+            .iter()
+            .any(|p| p.is_empty() || !p.bytes().all(|b| b.is_ascii_digit()))
+    {
+        return Err(invalid());
+    }
+
+    let mut components = [0; 3];
+    for (component, part) in components.iter_mut().zip(&parts) {
+        *component = part.parse().map_err(|_| invalid())?;
+    }
+    Ok(components)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::common::Connection;
+
+    fn connection() -> Connection {
+        serde_json::from_str(include_str!("../tests/fixtures/connection.json")).unwrap()
+    }
+
+    #[test]
+    fn mapping_populates_both_units() {
+        let mut item = connection().glucose_item;
+        item.value_in_mg_per_dl = 180.0;
+        item.value = 10.0;
+        item.glucose_units = 0;
+
+        let data = map_glucose_data(&item);
+        assert_eq!(data.mgdl, 180.0);
+        assert!((data.mmol - 9.99).abs() < 0.01);
+    }
+
+    #[test]
+    fn mixed_unit_items_map_to_the_same_mgdl() {
+        // Connection says mg/dL, but items recorded around a unit switch differ
+        let connection = connection();
+        assert_eq!(connection.uom, 1);
+
+        let mut mgdl_item = connection.glucose_item.clone();
+        mgdl_item.glucose_units = 1;
+        mgdl_item.value = 112.0;
+        let mut mmol_item = connection.glucose_item.clone();
+        mmol_item.glucose_units = 0;
+        mmol_item.value = 6.2;
+        let mut mmol_without_mgdl = mmol_item.clone();
+        mmol_without_mgdl.value_in_mg_per_dl = 0.0;
+
+        assert_eq!(map_glucose_data(&mgdl_item).mgdl, 112.0);
+        assert_eq!(map_glucose_data(&mmol_item).mgdl, 112.0);
+        assert!((map_glucose_data(&mmol_without_mgdl).mgdl - 6.2 * MGDL_PER_MMOL).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mapping_uses_the_factory_timestamp() {
+        let mut item = connection().glucose_item;
+        item.factory_timestamp = "1/2/2024 3:04:05 PM".to_string();
+        let expected = parse_timestamp("2024-01-02T15:04:05Z");
+        assert_eq!(Some(map_glucose_data(&item).date), expected);
+        assert_eq!(try_map_glucose_data(&item).ok().map(|r| r.date), expected);
+
+        item.factory_timestamp = "2024-01-02 15:04".to_string();
+        assert!(matches!(
+            try_map_glucose_data(&item),
+            Err(LibreLinkUpError::InvalidResponse(_))
+        ));
+    }
+
+    #[test]
+    fn api_versions_compare_numerically() {
+        assert!(api_version_parts("4.9").unwrap() < api_version_parts("4.16.0").unwrap());
+        assert!(api_version_parts("4.16.1").unwrap() > api_version_parts("4.16").unwrap());
+        assert_eq!(api_version_parts("4").unwrap(), [4, 0, 0]);
+    }
+}
@@ -0,0 +1,83 @@
+//! Comparison of dotted numeric version strings such as the API `version` header.
+//!
+//! Main entries: [`compare_versions`] and [`version_lt`]. Components are compared as numbers,
+//! so `"4.9.0"` is older than `"4.16.0"`, which plain string comparison gets wrong.
+
+use std::cmp::Ordering;
+
+/// Compare two dotted version strings component by component
+///
+/// Missing components count as `0`, so `"4.16"` equals `"4.16.0"`. Each component is read up
+/// to its first non-digit and anything after it is ignored, so `"4.16.0-beta"` compares like
+/// `"4.16.0"`; a component without leading digits (e.g. `"x"`) counts as `0`. Surrounding
+/// whitespace and a leading `v` are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::version::compare_versions;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(compare_versions("4.9.0", "4.16.0"), Ordering::Less);
+/// assert_eq!(compare_versions("4.16", "4.16.0"), Ordering::Equal);
+/// assert_eq!(compare_versions("4.16.1-rc1", "4.16.0"), Ordering::Greater);
+/// ```
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let (a, b) = (components(a), components(b));
+    (0..a.len().max(b.len()))
+        .map(|i| {
+            let part = |parts: &[u64]| parts.get(i).copied().unwrap_or(0);
+            part(&a).cmp(&part(&b))
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Whether version `a` is older than version `b`, see [`compare_versions`]
+///
+/// # Examples
+///
+/// ```
+/// use libre_link_up_api_client::version::version_lt;
+///
+/// assert!(version_lt("4.9.0", "4.16.0"));
+/// assert!(!version_lt("4.16", "4.16.0"));
+/// ```
+pub fn version_lt(a: &str, b: &str) -> bool {
+    compare_versions(a, b) == Ordering::Less
+}
+
+/// Numeric components of `version`, leniently parsed
+fn components(version: &str) -> Vec<u64> {
+    let version = version.trim();
+    let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+    version
+        .split('.')
+        .map(|part| {
+            let digits = part
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(part.len());
+            // Saturate absurdly long components instead of treating them as zero
+            part[..digits]
+                .parse()
+                .unwrap_or(if digits == 0 { 0 } else { u64::MAX })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn components_compare_numerically_and_leniently() {
+        assert_eq!(compare_versions("4.16.0", "4.16.0"), Ordering::Equal);
+        assert_eq!(compare_versions("4.10", "4.9.9"), Ordering::Greater);
+        assert_eq!(compare_versions("5", "4.99.99"), Ordering::Greater);
+        assert_eq!(compare_versions("4.16.0.1", "4.16"), Ordering::Greater);
+        assert_eq!(compare_versions(" v4.16 ", "4.16.0"), Ordering::Equal);
+        assert_eq!(compare_versions("4.x.1", "4.0.1"), Ordering::Equal);
+        assert_eq!(compare_versions("", "0.0"), Ordering::Equal);
+        assert!(version_lt("4.16.0", "99999999999999999999999"));
+    }
+}
@@ -24,6 +24,7 @@ mod integration {
             api_version: None,
             region: None,
             connection_identifier: None,
+            ..Default::default()
         };
 
         let result = LibreLinkUpClient::new(config);
@@ -45,7 +46,7 @@ mod integration {
 
         match result {
             Ok(data) => {
-                assert!(data.current.value > 0.0);
+                assert!(data.current.mgdl > 0.0);
                 assert!(!data.history.is_empty());
             }
             Err(e) => {
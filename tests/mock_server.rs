@@ -0,0 +1,558 @@
+//! End-to-end tests against a local mock of the LibreLinkUp API.
+//!
+//! The fixtures in `tests/fixtures` follow captured response shapes, so a change to the models
+//! that no longer accepts them (or a field Abbott renames in a new capture) fails here without
+//! needing real credentials.
+
+use futures::StreamExt;
+use libre_link_up_api_client::{
+    ClientConfig, LibreLinkUpClient, LibreLinkUpError, ReconnectPolicy, RetryConfig, TrendStrategy,
+    TrendType,
+};
+use std::time::Duration;
+use wiremock::matchers::{header, header_exists, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const PATIENT_ID: &str = "1d5e6f70-8a9b-11ee-b962-0242ac120002";
+const LOGIN_TOKEN: &str = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.e30.signature";
+
+fn fixture(name: &str) -> serde_json::Value {
+    let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+/// Mock server answering login, connections, and graph requests for [`PATIENT_ID`]; other
+/// paths get a 404, and more than one login fails the test
+async fn api(connections: serde_json::Value, graph: serde_json::Value) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/llu/auth/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("login.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/llu/connections"))
+        .and(header("authorization", format!("Bearer {}", LOGIN_TOKEN)))
+        .and(header_exists("account-id"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(connections))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/llu/connections/{}/graph", PATIENT_ID)))
+        .and(header("authorization", format!("Bearer {}", LOGIN_TOKEN)))
+        .and(header_exists("account-id"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(graph))
+        .mount(&server)
+        .await;
+    server
+}
+
+async fn logins(server: &MockServer) -> usize {
+    let requests = server.received_requests().await.unwrap();
+    requests
+        .iter()
+        .filter(|request| request.url.path() == "/llu/auth/login")
+        .count()
+}
+
+fn client(server: &MockServer) -> LibreLinkUpClient {
+    LibreLinkUpClient::new(ClientConfig {
+        username: "email@example.com".to_string(),
+        password: "password".to_string(),
+        base_url: Some(server.uri()),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+#[tokio::test]
+async fn read_maps_captured_responses() {
+    let server = api(fixture("connections.json"), fixture("graph.json")).await;
+    let client = client(&server);
+
+    let response = client.read().await.unwrap();
+    assert_eq!(response.current.mgdl, 112.0);
+    assert_eq!(response.current.trend, TrendType::Flat);
+    let target = response.target.unwrap();
+    assert_eq!((target.low, target.high), (70.0, 180.0));
+    assert_eq!(
+        response.current.date.to_rfc3339(),
+        "2023-11-14T10:15:00+00:00"
+    );
+    let history: Vec<(f64, String)> = response
+        .history
+        .iter()
+        .map(|reading| (reading.mgdl, reading.date.to_rfc3339()))
+        .collect();
+    assert_eq!(
+        history,
+        [
+            (95.0, "2023-11-14T09:45:00+00:00".to_string()),
+            (104.0, "2023-11-14T10:00:00+00:00".to_string()),
+        ]
+    );
+
+    // The token from login is reused rather than logging in again
+    assert_eq!(client.read_current().await.unwrap(), response.current);
+    let active = client.get_active_sensor().await.unwrap().unwrap();
+    assert_eq!(active.sensor.sn, "0M0008B8CT");
+    assert_eq!(logins(&server).await, 1);
+    assert_eq!(client.current_base_url().await, server.uri());
+}
+
+#[tokio::test]
+async fn renamed_fields_fail_to_parse() {
+    let mut graph = fixture("graph.json");
+    let data = graph["data"].as_object_mut().unwrap();
+    let items = data.remove("graphData").unwrap();
+    data.insert("glucoseHistory".to_string(), items);
+    let server = api(fixture("connections.json"), graph).await;
+
+    let client = client(&server);
+    let error = client.read().await.unwrap_err();
+    assert!(
+        matches!(&error, LibreLinkUpError::InvalidResponse(message) if message.contains("graphData")),
+        "{error:?}"
+    );
+
+    // The untyped body still carries the renamed field
+    let graph = client.read_raw_json().await.unwrap();
+    assert_eq!(graph["data"]["glucoseHistory"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn read_all_reports_each_patient_separately() {
+    let mut connections = fixture("connections.json");
+    let mut missing = connections["data"][0].clone();
+    missing["patientId"] = "missing-patient".into();
+    missing["firstName"] = "Jim".into();
+    connections["data"].as_array_mut().unwrap().push(missing);
+    let server = api(connections, fixture("graph.json")).await;
+
+    let results = client(&server).read_all().await.unwrap();
+    assert_eq!(results.len(), 2);
+    let (found, data) = &results[0];
+    assert_eq!(found.patient_id, PATIENT_ID);
+    assert_eq!(data.as_ref().unwrap().history.len(), 2);
+    let (missing, error) = &results[1];
+    assert_eq!(missing.first_name, "Jim");
+    assert!(
+        matches!(error, Err(LibreLinkUpError::ApiStatus { status: 404, .. })),
+        "{error:?}"
+    );
+}
+
+#[tokio::test]
+async fn freshest_connection_skips_missing_readings() {
+    let mut connections = fixture("connections.json");
+    let mut unread = connections["data"][0].clone();
+    unread["patientId"] = "ffffffff-unread".into();
+    unread["glucoseMeasurement"]["FactoryTimestamp"] = "".into();
+    let mut tied = connections["data"][0].clone();
+    tied["patientId"] = "00000000-tied".into();
+    let data = connections["data"].as_array_mut().unwrap();
+    data.push(unread.clone());
+    data.push(tied);
+    let server = api(connections, fixture("graph.json")).await;
+
+    // The unreadable reading is not taken as the newest; the equal ones go to the lowest ID
+    let (connection, current) = client(&server).freshest_connection().await.unwrap();
+    assert_eq!(connection.patient_id, "00000000-tied");
+    assert_eq!(current.date.to_rfc3339(), "2023-11-14T10:15:00+00:00");
+
+    let mut connections = fixture("connections.json");
+    connections["data"] = serde_json::json!([unread]);
+    let server = api(connections, fixture("graph.json")).await;
+    let error = client(&server).freshest_connection().await.unwrap_err();
+    assert!(
+        matches!(error, LibreLinkUpError::NoConnections),
+        "{error:?}"
+    );
+}
+
+#[tokio::test]
+async fn auto_version_adopts_the_server_minimum() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/llu/config/country"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "status": 0,
+            "data": {"minVersion": "4.20.0"}
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/llu/auth/login"))
+        .and(header("version", "4.20.0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("login.json")))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/llu/connections"))
+        .and(header("version", "4.20.0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("connections.json")))
+        .mount(&server)
+        .await;
+    let client = LibreLinkUpClient::new(ClientConfig {
+        username: "email@example.com".to_string(),
+        password: "password".to_string(),
+        base_url: Some(server.uri()),
+        auto_version: true,
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(client.api_version(), "4.16.0");
+
+    // Login and the requests after it only succeed with the adopted version
+    let connections = client.get_connections().await.unwrap();
+    assert_eq!(connections[0].patient_id, PATIENT_ID);
+    assert_eq!(client.api_version(), "4.20.0");
+
+    let requests = server.received_requests().await.unwrap();
+    let versions: Vec<(&str, &str)> = requests
+        .iter()
+        .map(|request| {
+            let version = request.headers.get("version").unwrap().to_str().unwrap();
+            (request.url.path(), version)
+        })
+        .collect();
+    assert_eq!(
+        versions,
+        [
+            ("/llu/config/country", "4.16.0"),
+            ("/llu/auth/login", "4.20.0"),
+            ("/llu/connections", "4.20.0"),
+        ]
+    );
+}
+
+/// Client for `server` that backs off for an hour after a failed poll
+fn slow_reconnect_client(server: &MockServer) -> LibreLinkUpClient {
+    LibreLinkUpClient::new(ClientConfig {
+        username: "email@example.com".to_string(),
+        password: "password".to_string(),
+        base_url: Some(server.uri()),
+        reconnect: ReconnectPolicy {
+            initial_delay: Duration::from_secs(3600),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+#[tokio::test]
+async fn sse_errors_are_yielded_before_the_backoff() {
+    let server = MockServer::start().await;
+    let client = slow_reconnect_client(&server);
+    let mut events = Box::pin(client.sse_stream(Duration::from_millis(10)));
+
+    let first = tokio::time::timeout(Duration::from_secs(5), events.next()).await;
+    assert!(matches!(first, Ok(Some(Err(_)))), "{first:?}");
+    // The next poll waits out the backoff
+    assert!(
+        tokio::time::timeout(Duration::from_millis(200), events.next())
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn averaged_stream_errors_are_yielded_before_the_backoff() {
+    let server = MockServer::start().await;
+    let client = slow_reconnect_client(&server);
+    let mut averages = Box::pin(client.read_averaged_stream(
+        3,
+        Duration::from_millis(10),
+        TrendStrategy::MostRecent,
+    ));
+
+    let first = tokio::time::timeout(Duration::from_secs(5), averages.next()).await;
+    assert!(matches!(first, Ok(Some(Err(_)))), "{first:?}");
+    assert!(
+        tokio::time::timeout(Duration::from_millis(200), averages.next())
+            .await
+            .is_err()
+    );
+}
+
+/// Mock server whose login succeeds exactly once and whose connections list answers `status`
+async fn failing_connections(status: u16) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/llu/auth/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("login.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/llu/connections"))
+        .respond_with(ResponseTemplate::new(status).insert_header("retry-after", "30"))
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn rate_limits_do_not_trigger_a_login() {
+    let server = failing_connections(429).await;
+    let error = client(&server).get_connections().await.unwrap_err();
+    assert!(
+        matches!(error, LibreLinkUpError::RateLimited { retry_after: Some(wait) } if wait == Duration::from_secs(30)),
+        "{error:?}"
+    );
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    server.verify().await;
+}
+
+#[tokio::test]
+async fn exhausted_retries_do_not_trigger_a_login() {
+    let server = failing_connections(503).await;
+    let client = LibreLinkUpClient::new(ClientConfig {
+        username: "email@example.com".to_string(),
+        password: "password".to_string(),
+        base_url: Some(server.uri()),
+        retry: Some(RetryConfig {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+        }),
+        ..Default::default()
+    })
+    .unwrap();
+
+    let error = client.get_connections().await.unwrap_err();
+    match error {
+        LibreLinkUpError::RetriesExhausted {
+            attempts, source, ..
+        } => {
+            assert_eq!(attempts, 3);
+            assert!(matches!(
+                *source,
+                LibreLinkUpError::ApiStatus { status: 503, .. }
+            ));
+        }
+        other => panic!("unexpected {other:?}"),
+    }
+    // One login, then the three attempts of a single retry batch
+    assert_eq!(server.received_requests().await.unwrap().len(), 4);
+    server.verify().await;
+}
+
+#[tokio::test]
+async fn streamed_parsing_matches_buffered() {
+    let server = api(fixture("connections.json"), fixture("graph.json")).await;
+    let streaming = LibreLinkUpClient::new(ClientConfig {
+        username: "email@example.com".to_string(),
+        password: "password".to_string(),
+        base_url: Some(server.uri()),
+        stream_json: true,
+        ..Default::default()
+    })
+    .unwrap();
+    let buffered = api(fixture("connections.json"), fixture("graph.json")).await;
+    assert_eq!(
+        streaming.read().await.unwrap(),
+        client(&buffered).read().await.unwrap()
+    );
+
+    let error = serde_json::json!({ "status": 920, "error": { "message": "RequiredNotFound" } });
+    let server = api(error, fixture("graph.json")).await;
+    let streaming = LibreLinkUpClient::new(ClientConfig {
+        username: "email@example.com".to_string(),
+        password: "password".to_string(),
+        base_url: Some(server.uri()),
+        stream_json: true,
+        ..Default::default()
+    })
+    .unwrap();
+    assert!(matches!(
+        streaming.get_connections().await,
+        Err(LibreLinkUpError::ApiError { status: 920, message }) if message == "RequiredNotFound"
+    ));
+}
+
+/// Error from logging in against a server that answers logins with `body`
+async fn login_error(body: serde_json::Value) -> LibreLinkUpError {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/llu/auth/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&server)
+        .await;
+    client(&server).force_login().await.unwrap_err()
+}
+
+#[tokio::test]
+async fn logins_without_a_token_are_rejected() {
+    let mut unknown = fixture("login.json");
+    unknown["data"]["session"] = unknown["data"]["authTicket"].take();
+    unknown["data"]
+        .as_object_mut()
+        .unwrap()
+        .remove("authTicket");
+    match login_error(unknown).await {
+        LibreLinkUpError::InvalidResponse(message) => {
+            assert!(message.contains("\"session\""), "{message}");
+            assert!(message.contains("\"token\":\"string\""), "{message}");
+            for value in [LOGIN_TOKEN, "Jane", "email@example.com"] {
+                assert!(!message.contains(value), "{message}");
+            }
+        }
+        other => panic!("unexpected {other:?}"),
+    }
+
+    let redirect = serde_json::json!({
+        "status": 0,
+        "data": { "redirect": false, "region": "eu" }
+    });
+    assert!(matches!(
+        login_error(redirect).await,
+        LibreLinkUpError::InvalidResponse(message) if message.contains("redirect")
+    ));
+}
+
+#[tokio::test]
+async fn login_steps_are_reported_without_guessing_an_endpoint() {
+    let server = MockServer::start().await;
+    let step = serde_json::json!({
+        "status": 4,
+        "data": {
+            "step": {
+                "type": "verifyEmail",
+                "componentName": "VerifyEmail",
+                "props": { "email": "email@example.com" }
+            },
+            "user": {
+                "id": "7c1d4e2a-8a9b-11ee-b962-0242ac120002",
+                "accountType": "pat",
+                "country": "DE",
+                "uiLanguage": "en-US"
+            },
+            "authTicket": { "token": "step-token", "expires": 0, "duration": 0 }
+        }
+    });
+    Mock::given(method("POST"))
+        .and(path("/llu/auth/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(step))
+        .mount(&server)
+        .await;
+    let client = client(&server);
+
+    assert!(matches!(
+        client.force_login().await,
+        Err(LibreLinkUpError::AdditionalActionRequired(name)) if name == "VerifyEmail"
+    ));
+    let pending = client.pending_login_step().await.unwrap();
+    assert_eq!(pending.step_type, "verifyEmail");
+    // Nothing but the login is requested
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn logins_without_consents_still_succeed() {
+    // Missing LibreLinkUp consents only hint at a patient account; the token is still usable
+    let mut patient = fixture("login.json");
+    patient["data"]["user"]["consents"] = serde_json::json!({});
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/llu/auth/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(patient))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client(&server);
+
+    client.force_login().await.unwrap();
+    assert!(client.is_authenticated().await);
+}
+
+#[tokio::test]
+async fn streamed_parsing_decodes_gzip_bodies() {
+    use flate2::{Compression, write::GzEncoder};
+    use std::io::Write;
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/llu/auth/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("login.json")))
+        .mount(&server)
+        .await;
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(fixture("connections.json").to_string().as_bytes())
+        .unwrap();
+    Mock::given(method("GET"))
+        .and(path("/llu/connections"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-encoding", "gzip")
+                .set_body_raw(encoder.finish().unwrap(), "application/json"),
+        )
+        .mount(&server)
+        .await;
+    // Without gzip support in the HTTP client, the body arrives still compressed
+    let client = LibreLinkUpClient::with_client(
+        ClientConfig {
+            username: "email@example.com".to_string(),
+            password: "password".to_string(),
+            base_url: Some(server.uri()),
+            stream_json: true,
+            ..Default::default()
+        },
+        reqwest::Client::builder().gzip(false).build().unwrap(),
+    )
+    .unwrap();
+
+    let connections = client.get_connections().await.unwrap();
+    assert_eq!(connections[0].patient_id, PATIENT_ID);
+}
+
+#[tokio::test]
+async fn captured_logins_keep_only_their_structure() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/llu/auth/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("login.json")))
+        .mount(&server)
+        .await;
+    let client = LibreLinkUpClient::new(ClientConfig {
+        username: "email@example.com".to_string(),
+        password: "password".to_string(),
+        base_url: Some(server.uri()),
+        capture_raw_responses: true,
+        ..Default::default()
+    })
+    .unwrap();
+
+    client.force_login().await.unwrap();
+    let captured = client.last_raw_response().unwrap();
+    assert!(captured.contains("\"authTicket\""), "{captured}");
+    for value in [LOGIN_TOKEN, "Jane", "email@example.com"] {
+        assert!(!captured.contains(value), "{captured}");
+    }
+}
+
+#[tokio::test]
+async fn bad_passwords_are_bad_credentials() {
+    let bad_password = serde_json::json!({
+        "status": 2,
+        "error": { "message": "notAuthenticated" }
+    });
+    assert!(matches!(
+        login_error(bad_password).await,
+        LibreLinkUpError::BadCredentials
+    ));
+}
+
+#[test]
+fn base_url_must_be_http() {
+    let result = LibreLinkUpClient::new(ClientConfig {
+        username: "email@example.com".to_string(),
+        password: "password".to_string(),
+        base_url: Some("ftp://127.0.0.1".to_string()),
+        ..Default::default()
+    });
+    assert!(matches!(result, Err(LibreLinkUpError::InvalidConfig(_))));
+}
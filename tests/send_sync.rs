@@ -0,0 +1,57 @@
+//! Compile-time checks that the client and its public types can be shared across threads
+//! (e.g. as `Arc<LibreLinkUpClient>` in axum/actix handlers or moved into spawned tasks).
+//!
+//! These tests fail to compile, rather than fail at runtime, if a non-`Send`/`Sync` field is
+//! introduced.
+
+use libre_link_up_api_client::{
+    ClientConfig, Connection, ConnectionIdentifier, LibreCgmData, LibreLinkUpClient,
+    LibreLinkUpError, ReadRawResponse, ReadResponse, ReconnectPolicy, Region, SessionState,
+    TimeSeries,
+};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+fn assert_send<T: Send>(_: &T) {}
+
+#[test]
+fn public_types_are_send_and_sync() {
+    assert_send_sync::<LibreLinkUpClient>();
+    assert_send_sync::<ClientConfig>();
+    assert_send_sync::<ConnectionIdentifier>();
+    assert_send_sync::<ReconnectPolicy>();
+    assert_send_sync::<LibreLinkUpError>();
+    assert_send_sync::<ReadResponse>();
+    assert_send_sync::<ReadRawResponse>();
+    assert_send_sync::<LibreCgmData>();
+    assert_send_sync::<Connection>();
+    assert_send_sync::<TimeSeries>();
+    assert_send_sync::<Region>();
+    assert_send_sync::<SessionState>();
+}
+
+#[test]
+fn request_futures_are_send() {
+    let client = LibreLinkUpClient::new(ClientConfig {
+        username: "email@example.com".to_string(),
+        password: "password".to_string(),
+        ..Default::default()
+    })
+    .unwrap();
+
+    // Futures are only constructed, never polled, so no requests are made
+    assert_send(&client.read());
+    assert_send(&client.read_raw());
+    assert_send(&client.read_raw_with_meta());
+    assert_send(&client.read_for("patient-id"));
+    assert_send(&client.get_user());
+    assert_send(&client.freshest_connection());
+    assert_send(&client.get_connections());
+    assert_send(&client.export_session());
+    assert_send(&client.current_region());
+    assert_send(&client.force_login());
+    assert_send(&client.read_all());
+    assert_send(&client.read_current());
+    assert_send(&client.get_active_sensor());
+    assert_send(&client.read_raw_json());
+}